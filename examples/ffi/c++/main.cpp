@@ -19,7 +19,7 @@ int main(int argc, char *argv[]) {
     else {
         char *private_key = filecoin_signer_extended_key_private_key(extended_key, error);
         assert(strcmp(private_key, "f15716d3b003b304b8055d9cc62e6b9c869d56cc930c3858d4d7c31f5f53f14a") == 0);
-        filecoin_signer_string_free(private_key);
+        filecoin_signer_secret_string_free(private_key);
     }
 
     filecoin_signer_extended_key_free(extended_key);
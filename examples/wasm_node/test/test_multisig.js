@@ -68,6 +68,15 @@ describeCall('createMultisig', function() {
   })
 
 
+  it('should predict the robust address of the created multisig', function() {
+    const multisig_create = dataTxs.create
+
+    const address = filecoin_signer.predictRobustAddress(multisig_create.message)
+
+    assert.strictEqual(address, 't2nzl23ysfwo53kl5syolbeh6j43sxx4hfmdhm56i')
+    assert.throws(() => filecoin_signer.predictRobustAddress({ ...multisig_create.message, method: 0 }), /Exec/)
+  })
+
   it('should return a serialized version of the create multisig transaction', function() {
     const multisig_create = dataTxs.create
 
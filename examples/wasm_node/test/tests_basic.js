@@ -33,6 +33,41 @@ describe('generateMnemonic', function() {
     const mnemonic = filecoin_signer.generateMnemonic()
     assert.strictEqual(mnemonic.split(' ').length, 24)
   })
+
+  it('should generate a 12 words french mnemonic', function() {
+    const mnemonic = filecoin_signer.generateMnemonic(12, 'fr')
+    assert.strictEqual(mnemonic.split(' ').length, 12)
+  })
+
+  it('should generate a mnemonic from entropy', function() {
+    const mnemonic = filecoin_signer.generateMnemonic(12, 'en', '00000000000000000000000000000000')
+    assert.strictEqual(mnemonic, 'abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about')
+  })
+
+  it('should fail to generate a mnemonic with an invalid word count', function() {
+    assert.throws(() => filecoin_signer.generateMnemonic(13), /Error generating key/)
+  })
+})
+
+describeCall('mnemonicValidate', function() {
+  it('should validate a mnemonic and detect its language', function() {
+    const validation = filecoin_signer.mnemonicValidate(dataWallet.mnemonic)
+
+    assert.strictEqual(validation.status, 'valid')
+    assert.strictEqual(validation.language_code, 'en')
+    assert.strictEqual(validation.word_count, 12)
+    assert.strictEqual(validation.seed_hexstring.length, 128)
+  })
+
+  it('should suggest words for a misspelled word', function() {
+    const mnemonic = dataWallet.mnemonic.replace('anxiety', 'anxeity')
+    const validation = filecoin_signer.mnemonicValidate(mnemonic)
+
+    assert.strictEqual(validation.status, 'invalid_words')
+    assert.strictEqual(validation.invalid_words[0].index, 7)
+    assert.deepStrictEqual(validation.invalid_words[0].suggestions, ['anxiety'])
+    assert.strictEqual(validation.seed_hexstring, null)
+  })
 })
 
 describeCall('keyDerive', function() {
@@ -228,6 +263,127 @@ describeCall('keyRecoverBLS', function() {
   })
 })
 
+describeCall('ethAddressToDelegated', function() {
+  it('should return the Ethereum and delegated addresses of a secp256k1 key', function() {
+    let recoveredKey = filecoin_signer.keyRecover(dataWallet.private_key, false)
+
+    assert.strictEqual(recoveredKey.eth_address, '0xE2f99104C3B7B50C1dE196ac98C9eadf389d455F')
+    assert.strictEqual(recoveredKey.delegated_address, 'f410f4l4zcbgdw62qyhpbs2wjrspk344j2rk7xe75ivi')
+
+    let blsKey = filecoin_signer.keyRecoverBLS(dataWallet.bls_private_key, true)
+    assert.strictEqual(blsKey.eth_address, undefined)
+  })
+
+  it('should convert between Ethereum and delegated addresses', function() {
+    const ethAddress = '0x52963EF50e27e06D72D59fcB4F3c2a687BE3cfEf'
+
+    assert.strictEqual(filecoin_signer.ethAddressToDelegated(ethAddress, false), 'f410fkkld55ioe7qg24wvt7fu6pbknb56ht7pt4zamxa')
+    assert.strictEqual(filecoin_signer.delegatedToEthAddress('t410fkkld55ioe7qg24wvt7fu6pbknb56ht7pt4zamxa'), ethAddress)
+  })
+
+  it('should reject an invalid checksum', function() {
+    assert.throws(() => filecoin_signer.ethAddressToDelegated('0x52963ef50e27e06D72D59fcB4F3c2a687BE3cfEf', false), /checksum/)
+    assert.throws(() => filecoin_signer.delegatedToEthAddress('f410fkkld55ioe7qg24wvt7fu6pbknb56ht7pt4zamxb'), /checksum/)
+  })
+})
+
+describeCall('addressInspect', function() {
+  it('should describe an address', function() {
+    const address = dataWallet.childs[3].address
+    const info = filecoin_signer.addressInspect(address)
+
+    assert.strictEqual(info.protocol, 'secp256k1')
+    assert.strictEqual(info.network, 'mainnet')
+    assert.strictEqual(info.checksum_valid, true)
+    assert.strictEqual(info.testnet_address, 't' + address.slice(1))
+    assert.strictEqual(info.id, null)
+  })
+
+  it('should report an invalid checksum', function() {
+    const info = filecoin_signer.addressInspect('f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6fpxba')
+
+    assert.strictEqual(info.checksum_valid, false)
+    assert.strictEqual(info.mainnet_address, null)
+  })
+
+  it('should convert between address forms', function() {
+    const address = dataWallet.childs[3].address
+    const bytes = filecoin_signer.addressConvert(address, 'bytes')
+
+    assert.strictEqual(bytes, '011eaf1c8a4bbfeeb0870b1745b1f57503470b7116')
+    assert.strictEqual(filecoin_signer.addressFromBytes(bytes, false), address)
+    assert.strictEqual(filecoin_signer.addressConvert(address, 'testnet'), 't' + address.slice(1))
+    assert.throws(() => filecoin_signer.addressConvert(address, 'eth'), /f410/)
+  })
+})
+
+describeCall('verifySignedMessage', function() {
+  it('should verify a mainnet signed message', function() {
+    const message = {
+      to: 'f17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy',
+      from: 'f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba',
+      nonce: 1,
+      value: '100000',
+      gaslimit: 25000,
+      gasfeecap: '2500',
+      gaspremium: '2500',
+      method: 0,
+      params: '',
+    }
+
+    const signedMessage = filecoin_signer.transactionSign(message, dataWallet.private_key)
+    assert.strictEqual(filecoin_signer.verifySignedMessage(signedMessage), true)
+
+    signedMessage.message.nonce = 2
+    assert.strictEqual(filecoin_signer.verifySignedMessage(signedMessage), false)
+  })
+})
+
+describeCall('signBytes', function() {
+  it('should sign and verify data like WalletSign', function() {
+    const address = 'f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba'
+    const data = Buffer.from('filecoin')
+
+    const signature = filecoin_signer.signBytes(data, address, dataWallet.private_key, false)
+
+    assert.strictEqual(signature.type, 1)
+    assert.strictEqual(
+      Buffer.from(signature.data, 'base64').toString('hex'),
+      '1887e6beb5e42c3bd60737a3a2155f24c0aed8082db3ba676fb4a1683f17b5937dcdcdac84abd0bcc7fb8fddc1fe718d09ad691261c62a7d2bc176096ed32d0f01',
+    )
+    assert.strictEqual(filecoin_signer.verifyBytes(signature.data, address, data, false), true)
+    assert.strictEqual(filecoin_signer.verifyBytes(signature.data, address, data, true), false)
+  })
+
+  it('should sign behind the domain separation prefix', function() {
+    const blsKey = filecoin_signer.keyRecoverBLS(dataWallet.bls_private_key, true)
+    const data = Buffer.from('filecoin')
+
+    const signature = filecoin_signer.signBytes(data, blsKey.address, blsKey.private_base64, true)
+
+    assert.strictEqual(signature.type, 2)
+    assert.strictEqual(filecoin_signer.verifyBytes(signature.data, blsKey.address, data, true), true)
+    assert.strictEqual(filecoin_signer.verifyBytes(signature.data, blsKey.address, data, false), false)
+  })
+})
+
+describeCall('keyImportLotus', function() {
+  it('should import a secp256k1 key exported by lotus', function() {
+    let importedKey = filecoin_signer.keyImportLotus(dataWallet.lotus_key, false)
+
+    assert.strictEqual(importedKey.private_base64, dataWallet.private_key)
+    assert.strictEqual(importedKey.address, dataWallet.childs[3].address)
+    assert.strictEqual(filecoin_signer.keyExportLotus(importedKey), dataWallet.lotus_key)
+  })
+
+  it('should import a BLS key exported by lotus', function() {
+    let importedKey = filecoin_signer.keyImportLotus(dataWallet.bls_lotus_key, true)
+
+    assert.strictEqual(importedKey.address, dataWallet.bls_address)
+    assert.strictEqual(filecoin_signer.keyExportLotus(importedKey), dataWallet.bls_lotus_key)
+  })
+})
+
 describe('transactionSerialize', function() {
   it('should serialize transaction', function() {
     assert.strictEqual(dataTxs[0].cbor, filecoin_signer.transactionSerialize(dataTxs[0].transaction))
@@ -318,6 +474,69 @@ describe('transactionSign', function() {
   })
 })
 
+describeCall('transactionSignBatch', function() {
+  it('should sign and verify a batch of transactions', function() {
+    const messages = [1, 2, 3].map(nonce => ({
+      to: 't17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy',
+      from: 't1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba',
+      nonce,
+      value: '100000',
+      gaslimit: 25000,
+      gasfeecap: '2500',
+      gaspremium: '2500',
+      method: 0,
+      params: '',
+    }))
+    messages[1].to = 't1invalid'
+
+    const results = filecoin_signer.transactionSignBatch(messages, dataWallet.private_key)
+
+    assert.strictEqual(results.length, 3)
+    assert('error' in results[1])
+    assert.deepStrictEqual(results[0].ok, filecoin_signer.transactionSign(messages[0], dataWallet.private_key))
+
+    const signatures = [results[0].ok, results[2].ok].map(signedMessage => ({
+      signature: Buffer.from(signedMessage.signature.data, 'base64'),
+      message: filecoin_signer.transactionSerialize(signedMessage.message),
+    }))
+    signatures.push({ signature: signatures[0].signature, message: signatures[1].message })
+
+    assert.deepStrictEqual(filecoin_signer.verifySignatureBatch(signatures), [{ ok: true }, { ok: true }, { ok: false }])
+  })
+})
+
+describeCall('messageValidate', function() {
+  const message = {
+    to: 't17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy',
+    from: 't1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba',
+    nonce: 1,
+    value: '100000',
+    gaslimit: 25000,
+    gasfeecap: '2500',
+    gaspremium: '5000',
+    method: 0,
+    params: '',
+  }
+
+  it('should report errors and warnings', function() {
+    assert.deepStrictEqual(filecoin_signer.messageValidate({ ...message, gaspremium: '2500' }), { errors: [], warnings: [] })
+
+    const validation = filecoin_signer.messageValidate(message)
+    assert.strictEqual(validation.errors.length, 1)
+    assert.strictEqual(validation.errors[0].field, 'gas_premium')
+    assert.strictEqual(validation.errors[0].kind, 'gas_premium_above_fee_cap')
+
+    const params = Buffer.from([0xff]).toString('base64')
+    const invalidParams = filecoin_signer.messageValidate({ ...message, gaspremium: '2500', method: 2, params }, 'fil/5/multisig')
+    assert.strictEqual(invalidParams.errors[0].kind, 'invalid_params')
+  })
+
+  it('should refuse to sign an invalid message in strict mode', function() {
+    assert(filecoin_signer.transactionSign(message, dataWallet.private_key))
+    assert.throws(() => filecoin_signer.transactionSignStrict(message, dataWallet.private_key), /invalid message/)
+  })
+})
+
 describe('transactionSignLotus', function() {
   it('should sign transaction and return a Lotus compatible json string', function() {
     let data = fs.readFileSync('../../test_vectors/signed_message.json')
@@ -513,6 +732,48 @@ describeCall('BLS support', function() {
   }
 })
 
+describeCall('aggregateBLSMessages', function() {
+  it('should aggregate the signatures of BLS messages', function() {
+    const blsKey = filecoin_signer.keyRecoverBLS(dataWallet.bls_private_key, true)
+
+    const signedMessages = [1, 2].map(nonce => filecoin_signer.transactionSign({
+      to: 't17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy',
+      from: blsKey.address,
+      nonce,
+      value: '100000',
+      gaslimit: 25000,
+      gasfeecap: '2500',
+      gaspremium: '2500',
+      method: 0,
+      params: '',
+    }, dataWallet.bls_private_key))
+
+    const aggregate = filecoin_signer.aggregateBLSMessages(signedMessages)
+
+    assert.strictEqual(aggregate.signature.type, 2)
+    assert.deepStrictEqual(aggregate.cids, signedMessages.map(signedMessage => filecoin_signer.getCid(signedMessage.message)))
+
+    const signature = filecoin_signer.aggregateBLSSignatures(signedMessages.map(signedMessage => signedMessage.signature.data))
+    assert.strictEqual(signature.data, aggregate.signature.data)
+  })
+
+  it('should refuse secp256k1 signed messages', function() {
+    const signedMessage = filecoin_signer.transactionSign({
+      to: 't17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy',
+      from: 't1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba',
+      nonce: 1,
+      value: '100000',
+      gaslimit: 25000,
+      gasfeecap: '2500',
+      gaspremium: '2500',
+      method: 0,
+      params: '',
+    }, dataWallet.private_key)
+
+    assert.throws(() => filecoin_signer.aggregateBLSMessages([signedMessage]), /not BLS signed/)
+  })
+})
+
 //////////////////////////////////////
 // Parameterized tests
 const tests_vectors_path = '../manual_testvectors.json'
@@ -7,6 +7,8 @@ use crate::service::error::RemoteNode::{
 };
 use crate::service::error::ServiceError;
 use abscissa_core::tracing::info;
+use filecoin_signer::discovery::{AccountState, ChainBackend, ChainBackendFuture};
+use filecoin_signer::error::SignerError;
 use jsonrpc_core::response::Output::{Failure, Success};
 use jsonrpc_core::{Id, MethodCall, Params, Response, Version};
 use serde_json::value::Value;
@@ -139,6 +141,68 @@ pub async fn get_status(url: &str, jwt: &str, cid_message: Value) -> Result<Valu
     Ok(result)
 }
 
+pub async fn get_account_state(
+    url: &str,
+    jwt: &str,
+    addr: &str,
+) -> Result<AccountState, ServiceError> {
+    let call_id = CALL_ID.fetch_add(1, Ordering::SeqCst);
+
+    // Prepare request
+    let m = MethodCall {
+        jsonrpc: Some(Version::V2),
+        method: "Filecoin.StateGetActor".to_owned(),
+        params: Params::Array(vec![Value::from(addr), Value::Null]),
+        id: Id::Num(call_id),
+    };
+
+    let resp = make_rpc_call(url, jwt, &m).await?;
+
+    // Handle response
+    let state = match resp {
+        Response::Single(Success(s)) => {
+            let nonce = s.result["Nonce"]
+                .as_u64()
+                .ok_or(ServiceError::RemoteNode(InvalidStatusRequest))?;
+            let balance = s.result["Balance"]
+                .as_str()
+                .ok_or(ServiceError::RemoteNode(InvalidStatusRequest))?;
+
+            AccountState {
+                nonce,
+                balance: balance.to_string(),
+            }
+        }
+        // An address never used on chain has no actor
+        Response::Single(Failure(f)) if f.error.message.contains("actor not found") => {
+            AccountState {
+                nonce: 0,
+                balance: "0".to_string(),
+            }
+        }
+        Response::Single(Failure(f)) => return Err(ServiceError::RemoteNode(JSONRPC(f.error))),
+        _ => return Err(ServiceError::RemoteNode(UnknownError)),
+    };
+
+    Ok(state)
+}
+
+/// Chain backend used for account discovery, querying the remote Lotus node
+pub struct LotusBackend {
+    pub url: String,
+    pub jwt: String,
+}
+
+impl ChainBackend for LotusBackend {
+    fn account_state<'a>(&'a self, address: &'a str) -> ChainBackendFuture<'a, AccountState> {
+        Box::pin(async move {
+            get_account_state(&self.url, &self.jwt, address)
+                .await
+                .map_err(|err| SignerError::GenericString(err.to_string()))
+        })
+    }
+}
+
 pub async fn is_mainnet(url: &str, jwt: &str) -> Result<bool, ServiceError> {
     let call_id = CALL_ID.fetch_add(1, Ordering::SeqCst);
 
@@ -38,10 +38,27 @@ async fn v0_post_methods(
         "key_generate_mnemonic" => methods::key_generate_mnemonic(method_call, config).await,
         "key_derive" => methods::key_derive(method_call, config).await,
         "key_derive_from_seed" => methods::key_derive_from_seed(method_call, config).await,
+        "key_derive_bls" => methods::key_derive_bls(method_call, config).await,
+        "key_generate_bls" => methods::key_generate_bls(method_call, config).await,
+        "mnemonic_validate" => methods::mnemonic_validate(method_call, config).await,
+        "key_discover" => methods::key_discover(method_call, config).await,
+        "key_import_lotus" => methods::key_import_lotus(method_call, config).await,
+        "key_export_lotus" => methods::key_export_lotus(method_call, config).await,
         "transaction_serialize" => methods::transaction_serialize(method_call, config).await,
         "transaction_parse" => methods::transaction_parse(method_call, config).await,
+        "address_inspect" => methods::address_inspect(method_call, config).await,
+        "address_convert" => methods::address_convert(method_call, config).await,
+        "predict_robust_address" => methods::predict_robust_address(method_call, config).await,
         "sign_transaction" => methods::sign_transaction(method_call, config).await,
+        "sign_transaction_batch" => methods::sign_transaction_batch(method_call, config).await,
+        "message_validate" => methods::message_validate(method_call, config).await,
         "verify_signature" => methods::verify_signature(method_call, config).await,
+        "verify_signature_batch" => methods::verify_signature_batch(method_call, config).await,
+        "verify_signed_message" => methods::verify_signed_message(method_call, config).await,
+        "sign_bytes" => methods::sign_bytes(method_call, config).await,
+        "verify_bytes" => methods::verify_bytes(method_call, config).await,
+        "aggregate_bls_signatures" => methods::aggregate_bls_signatures(method_call, config).await,
+        "aggregate_bls_messages" => methods::aggregate_bls_messages(method_call, config).await,
         "get_status" => methods::get_status(method_call, config).await,
         "get_nonce" => methods::get_nonce(method_call, config).await,
         "send_signed_tx" => methods::send_signed_tx(method_call, config).await,
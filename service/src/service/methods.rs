@@ -3,10 +3,15 @@
 use crate::config::RemoteNodeSection;
 use crate::service::client;
 use crate::service::error::ServiceError;
-use filecoin_signer::api::{SignedMessageAPI, UnsignedMessageAPI};
-use filecoin_signer::signature::Signature;
+use filecoin_signer::address::AddressFormat;
+use filecoin_signer::api::{
+    BatchResultAPI, LotusKeyType, SignatureAPI, SignedMessageAPI, UnsignedMessageAPI,
+};
+use filecoin_signer::discovery::{DiscoveryLayout, DiscoveryOptions};
+use filecoin_signer::error::SignerError;
+use filecoin_signer::signature::{Signature, SignatureBLS};
 use filecoin_signer::{CborBuffer, PrivateKey};
-use jsonrpc_core::{MethodCall, Success, Version};
+use jsonrpc_core::{MethodCall, Params, Success, Version};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use serde_json::Value;
@@ -21,6 +26,15 @@ pub struct SendSignedTxParamsAPI {
 pub struct SignTransactionParamsAPI {
     pub transaction: UnsignedMessageAPI,
     pub prvkey_base64: String,
+    #[serde(default)]
+    pub strict: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageValidateParamsAPI {
+    pub transaction: UnsignedMessageAPI,
+    #[serde(default)]
+    pub actor_type: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -35,12 +49,68 @@ pub struct KeyDeriveParamsAPI {
     #[serde(default)]
     pub password: String,
     pub language_code: String,
+    #[serde(default)]
+    pub delegated: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct KeyDeriveFromSeedParamsAPI {
     pub seed: String,
     pub path: String,
+    #[serde(default)]
+    pub delegated: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KeyGenerateBLSParamsAPI {
+    pub testnet: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MnemonicValidateParamsAPI {
+    pub mnemonic: String,
+    #[serde(default)]
+    pub password: String,
+    #[serde(default)]
+    pub language_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KeyDiscoverParamsAPI {
+    pub mnemonic: String,
+    #[serde(default)]
+    pub password: String,
+    #[serde(default)]
+    pub language_code: Option<String>,
+    #[serde(default)]
+    pub testnet: bool,
+    #[serde(default)]
+    pub gap_limit: Option<u32>,
+    #[serde(default)]
+    pub layouts: Option<Vec<DiscoveryLayout>>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AddressInspectParamsAPI {
+    pub address: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AddressConvertParamsAPI {
+    pub address: String,
+    pub format: AddressFormat,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KeyImportLotusParamsAPI {
+    pub lotus_key: String,
+    pub testnet: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KeyExportLotusParamsAPI {
+    pub prvkey_base64: String,
+    pub key_type: LotusKeyType,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -48,6 +118,10 @@ pub struct KeyDeriveResultAPI {
     pub private_base64: String,
     pub public_hexstring: String,
     pub address: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eth_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegated_address: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -62,20 +136,100 @@ pub struct VerifySignatureParamsAPI {
     pub message_hex: String,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignTransactionBatchParamsAPI {
+    pub transactions: Vec<UnsignedMessageAPI>,
+    pub prvkey_base64: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct VerifySignatureBatchParamsAPI {
+    pub signatures: Vec<VerifySignatureParamsAPI>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignBytesParamsAPI {
+    pub data_hex: String,
+    pub address: String,
+    pub prvkey_base64: String,
+    #[serde(default)]
+    pub prefixed: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct VerifyBytesParamsAPI {
+    pub signature_hex: String,
+    pub address: String,
+    pub data_hex: String,
+    #[serde(default)]
+    pub prefixed: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AggregateBLSSignaturesParamsAPI {
+    pub signatures_hex: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AggregateBLSMessagesParamsAPI {
+    pub signed_messages: Vec<SignedMessageAPI>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GetStatusParamsAPI {
     pub cid_message: String,
 }
 
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct KeyGenerateMnemonicParamsAPI {
+    #[serde(default)]
+    pub word_count: Option<usize>,
+    #[serde(default)]
+    pub language_code: Option<String>,
+    #[serde(default)]
+    pub entropy_hexstring: Option<String>,
+}
+
 pub async fn key_generate_mnemonic(
     c: MethodCall,
     _: RemoteNodeSection,
 ) -> Result<Success, ServiceError> {
-    let mnemonic = filecoin_signer::key_generate_mnemonic()?;
+    let params = match c.params {
+        Params::None => KeyGenerateMnemonicParamsAPI::default(),
+        params => params.parse::<KeyGenerateMnemonicParamsAPI>()?,
+    };
+
+    let language_code = params
+        .language_code
+        .unwrap_or_else(|| filecoin_signer::DEFAULT_MNEMONIC_LANGUAGE_CODE.to_string());
+
+    let mnemonic = match params.entropy_hexstring {
+        Some(entropy_hexstring) => {
+            let entropy = hex::decode(&entropy_hexstring)?;
+            let mnemonic =
+                filecoin_signer::key_generate_mnemonic_from_entropy(&entropy, &language_code)?;
+
+            if let Some(word_count) = params.word_count {
+                if mnemonic.0.split_whitespace().count() != word_count {
+                    return Err(ServiceError::Signer(SignerError::GenericString(
+                        "Entropy size does not match word count".to_string(),
+                    )));
+                }
+            }
+
+            mnemonic
+        }
+        None => filecoin_signer::key_generate_mnemonic_with_options(
+            params
+                .word_count
+                .unwrap_or(filecoin_signer::DEFAULT_MNEMONIC_WORD_COUNT),
+            &language_code,
+        )?,
+    };
 
     let so = Success {
         jsonrpc: Some(Version::V2),
-        result: Value::from(mnemonic.0),
+        result: Value::from(mnemonic.0.as_str()),
         id: c.id,
     };
 
@@ -92,10 +246,18 @@ pub async fn key_derive(c: MethodCall, _: RemoteNodeSection) -> Result<Success,
         &params.language_code,
     )?;
 
+    let (eth_address, delegated_address) = if params.delegated {
+        (key_address.eth_address()?, key_address.delegated_address()?)
+    } else {
+        (None, None)
+    };
+
     let result = KeyDeriveResultAPI {
         public_hexstring: hex::encode(&key_address.public_key.to_vec()),
         private_base64: base64::encode(&key_address.private_key.0),
         address: key_address.address,
+        eth_address,
+        delegated_address,
     };
 
     let result_json = serde_json::to_value(&result)?;
@@ -119,10 +281,47 @@ pub async fn key_derive_from_seed(
 
     let key_address = filecoin_signer::key_derive_from_seed(&seed, &params.path)?;
 
+    let (eth_address, delegated_address) = if params.delegated {
+        (key_address.eth_address()?, key_address.delegated_address()?)
+    } else {
+        (None, None)
+    };
+
+    let result = KeyDeriveResultAPI {
+        public_hexstring: hex::encode(&key_address.public_key.to_vec()),
+        private_base64: base64::encode(&key_address.private_key.0),
+        address: key_address.address,
+        eth_address,
+        delegated_address,
+    };
+
+    let result_json = serde_json::to_value(&result)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: result_json,
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn key_derive_bls(c: MethodCall, _: RemoteNodeSection) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<KeyDeriveParamsAPI>()?;
+
+    let key_address = filecoin_signer::key_derive_bls(
+        &params.mnemonic,
+        &params.path,
+        &params.password,
+        &params.language_code,
+    )?;
+
     let result = KeyDeriveResultAPI {
         public_hexstring: hex::encode(&key_address.public_key.to_vec()),
         private_base64: base64::encode(&key_address.private_key.0),
         address: key_address.address,
+        eth_address: None,
+        delegated_address: None,
     };
 
     let result_json = serde_json::to_value(&result)?;
@@ -136,6 +335,153 @@ pub async fn key_derive_from_seed(
     Ok(so)
 }
 
+pub async fn key_generate_bls(
+    c: MethodCall,
+    _: RemoteNodeSection,
+) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<KeyGenerateBLSParamsAPI>()?;
+
+    let key_address = filecoin_signer::key_generate_bls(params.testnet)?;
+
+    let result = KeyDeriveResultAPI {
+        public_hexstring: hex::encode(&key_address.public_key.to_vec()),
+        private_base64: base64::encode(&key_address.private_key.0),
+        address: key_address.address,
+        eth_address: None,
+        delegated_address: None,
+    };
+
+    let result_json = serde_json::to_value(&result)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: result_json,
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn mnemonic_validate(
+    c: MethodCall,
+    _: RemoteNodeSection,
+) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<MnemonicValidateParamsAPI>()?;
+
+    let validation = filecoin_signer::mnemonic_validate(
+        &params.mnemonic,
+        &params.password,
+        params.language_code.as_deref(),
+    )?;
+
+    let result_json = serde_json::to_value(&validation)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: result_json,
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn key_discover(
+    c: MethodCall,
+    config: RemoteNodeSection,
+) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<KeyDiscoverParamsAPI>()?;
+
+    let mut options = DiscoveryOptions {
+        testnet: params.testnet,
+        ..DiscoveryOptions::default()
+    };
+    if let Some(gap_limit) = params.gap_limit {
+        options.gap_limit = gap_limit;
+    }
+    if let Some(layouts) = params.layouts {
+        options.layouts = layouts;
+    }
+
+    let language_code = params
+        .language_code
+        .unwrap_or_else(|| filecoin_signer::DEFAULT_MNEMONIC_LANGUAGE_CODE.to_string());
+
+    let backend = client::LotusBackend {
+        url: config.url,
+        jwt: config.jwt,
+    };
+
+    let accounts = filecoin_signer::key_discover(
+        &backend,
+        &params.mnemonic,
+        &params.password,
+        &language_code,
+        &options,
+    )
+    .await?;
+
+    let result_json = serde_json::to_value(&accounts)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: result_json,
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn key_import_lotus(
+    c: MethodCall,
+    _: RemoteNodeSection,
+) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<KeyImportLotusParamsAPI>()?;
+
+    let key_address = filecoin_signer::key_import_lotus(&params.lotus_key, params.testnet)?;
+
+    let result = KeyDeriveResultAPI {
+        public_hexstring: hex::encode(&key_address.public_key.to_vec()),
+        private_base64: base64::encode(&key_address.private_key.0),
+        address: key_address.address,
+        eth_address: None,
+        delegated_address: None,
+    };
+
+    let result_json = serde_json::to_value(&result)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: result_json,
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn key_export_lotus(
+    c: MethodCall,
+    _: RemoteNodeSection,
+) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<KeyExportLotusParamsAPI>()?;
+
+    let private_key = PrivateKey::try_from(params.prvkey_base64)?;
+
+    let key_address = match params.key_type {
+        LotusKeyType::Secp256k1 => filecoin_signer::key_recover(&private_key, false)?,
+        LotusKeyType::BLS => filecoin_signer::key_recover_bls(&private_key, false)?,
+    };
+
+    let lotus_key = filecoin_signer::key_export_lotus(&key_address)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: Value::from(lotus_key),
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
 pub async fn transaction_serialize(
     c: MethodCall,
     _: RemoteNodeSection,
@@ -152,6 +498,50 @@ pub async fn transaction_serialize(
     Ok(so)
 }
 
+pub async fn address_inspect(c: MethodCall, _: RemoteNodeSection) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<AddressInspectParamsAPI>()?;
+    let info = filecoin_signer::address_inspect(&params.address)?;
+
+    let result_json = serde_json::to_value(&info)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: result_json,
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn address_convert(c: MethodCall, _: RemoteNodeSection) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<AddressConvertParamsAPI>()?;
+    let address = filecoin_signer::address_convert(&params.address, params.format)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: Value::from(address),
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn predict_robust_address(
+    c: MethodCall,
+    _: RemoteNodeSection,
+) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<UnsignedMessageAPI>()?;
+    let address = filecoin_signer::predict_robust_address(&params)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: Value::from(address),
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
 pub async fn transaction_parse(
     c: MethodCall,
     _: RemoteNodeSection,
@@ -180,7 +570,11 @@ pub async fn sign_transaction(
 
     let private_key = PrivateKey::try_from(params.prvkey_base64)?;
 
-    let signed_message = filecoin_signer::transaction_sign(&params.transaction, &private_key)?;
+    let signed_message = if params.strict {
+        filecoin_signer::transaction_sign_strict(&params.transaction, &private_key)?
+    } else {
+        filecoin_signer::transaction_sign(&params.transaction, &private_key)?
+    };
 
     let so = Success {
         jsonrpc: Some(Version::V2),
@@ -191,6 +585,24 @@ pub async fn sign_transaction(
     Ok(so)
 }
 
+pub async fn message_validate(
+    c: MethodCall,
+    _: RemoteNodeSection,
+) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<MessageValidateParamsAPI>()?;
+
+    let validation =
+        filecoin_signer::message_validate(&params.transaction, params.actor_type.as_deref());
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: serde_json::to_value(&validation)?,
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
 pub async fn verify_signature(
     c: MethodCall,
     _: RemoteNodeSection,
@@ -211,6 +623,153 @@ pub async fn verify_signature(
     Ok(so)
 }
 
+pub async fn sign_transaction_batch(
+    c: MethodCall,
+    _: RemoteNodeSection,
+) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<SignTransactionBatchParamsAPI>()?;
+
+    let private_key = PrivateKey::try_from(params.prvkey_base64)?;
+
+    let results: Vec<BatchResultAPI<SignedMessageAPI>> =
+        filecoin_signer::transaction_sign_batch(&params.transactions, &private_key)
+            .into_iter()
+            .map(BatchResultAPI::from)
+            .collect();
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: serde_json::to_value(&results)?,
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn verify_signature_batch(
+    c: MethodCall,
+    _: RemoteNodeSection,
+) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<VerifySignatureBatchParamsAPI>()?;
+
+    let signatures = params
+        .signatures
+        .into_iter()
+        .map(|params| {
+            let signature = Signature::try_from(params.signature_hex)?;
+            let message = CborBuffer(hex::decode(&params.message_hex)?);
+
+            Ok((signature, message))
+        })
+        .collect::<Result<Vec<_>, SignerError>>()?;
+
+    let results: Vec<BatchResultAPI<bool>> = filecoin_signer::verify_signature_batch(&signatures)
+        .into_iter()
+        .map(BatchResultAPI::from)
+        .collect();
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: serde_json::to_value(&results)?,
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn verify_signed_message(
+    c: MethodCall,
+    _: RemoteNodeSection,
+) -> Result<Success, ServiceError> {
+    let signed_message = c.params.parse::<SignedMessageAPI>()?;
+
+    let result = filecoin_signer::verify_signed_message(&signed_message)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: Value::from(result),
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn sign_bytes(c: MethodCall, _: RemoteNodeSection) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<SignBytesParamsAPI>()?;
+
+    let private_key = PrivateKey::try_from(params.prvkey_base64)?;
+    let data = hex::decode(&params.data_hex)?;
+
+    let signature =
+        filecoin_signer::sign_bytes(&data, &params.address, &private_key, params.prefixed)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: serde_json::to_value(&SignatureAPI::from(&signature))?,
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn verify_bytes(c: MethodCall, _: RemoteNodeSection) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<VerifyBytesParamsAPI>()?;
+
+    let signature = Signature::try_from(params.signature_hex)?;
+    let data = hex::decode(&params.data_hex)?;
+
+    let result =
+        filecoin_signer::verify_bytes(&signature, &params.address, &data, params.prefixed)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: Value::from(result),
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn aggregate_bls_signatures(
+    c: MethodCall,
+    _: RemoteNodeSection,
+) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<AggregateBLSSignaturesParamsAPI>()?;
+
+    let signatures = params
+        .signatures_hex
+        .into_iter()
+        .map(SignatureBLS::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let signature = filecoin_signer::aggregate_bls_signatures(&signatures)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: serde_json::to_value(&SignatureAPI::from(&Signature::SignatureBLS(signature)))?,
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
+pub async fn aggregate_bls_messages(
+    c: MethodCall,
+    _: RemoteNodeSection,
+) -> Result<Success, ServiceError> {
+    let params = c.params.parse::<AggregateBLSMessagesParamsAPI>()?;
+
+    let aggregate = filecoin_signer::aggregate_bls_messages(&params.signed_messages)?;
+
+    let so = Success {
+        jsonrpc: Some(Version::V2),
+        result: serde_json::to_value(&aggregate)?,
+        id: c.id,
+    };
+
+    Ok(so)
+}
+
 pub async fn get_status(c: MethodCall, config: RemoteNodeSection) -> Result<Success, ServiceError> {
     let call_params = c.params.parse::<GetStatusParamsAPI>()?;
     let params = json!({"/": call_params.cid_message.to_string()});
@@ -266,7 +825,11 @@ pub async fn send_sign(c: MethodCall, config: RemoteNodeSection) -> Result<Succe
     let private_key = PrivateKey::try_from(params.prvkey_base64)?;
 
     // signed message
-    let signed_message = filecoin_signer::transaction_sign(&params.transaction, &private_key)?;
+    let signed_message = if params.strict {
+        filecoin_signer::transaction_sign_strict(&params.transaction, &private_key)?
+    } else {
+        filecoin_signer::transaction_sign(&params.transaction, &private_key)?
+    };
 
     let result = client::is_mainnet(&config.url, &config.jwt).await?;
 
@@ -298,8 +861,16 @@ pub async fn send_sign(c: MethodCall, config: RemoteNodeSection) -> Result<Succe
 
 #[cfg(test)]
 mod tests {
-    use crate::service::methods::get_status;
+    use std::convert::TryFrom;
+
+    use crate::service::methods::{
+        address_convert, address_inspect, aggregate_bls_messages, get_status, key_derive,
+        key_generate_mnemonic, message_validate, sign_bytes, sign_transaction,
+        sign_transaction_batch, verify_bytes, verify_signature_batch, verify_signed_message,
+    };
     use crate::service::test_helper::tests::get_remote_credentials;
+    use filecoin_signer::api::{SignedMessageAPI, UnsignedMessageAPI};
+    use filecoin_signer::PrivateKey;
     use jsonrpc_core::{Id, MethodCall, Params, Version};
     use serde_json::json;
 
@@ -365,4 +936,357 @@ mod tests {
 
         assert!(status.is_err());
     }
+
+    #[tokio::test]
+    async fn generate_mnemonic_from_entropy() {
+        let params_str = json!({
+            "word_count": 12,
+            "language_code": "en",
+            "entropy_hexstring": "00000000000000000000000000000000"
+        });
+        let params: Params =
+            serde_json::from_str(&params_str.to_string()).expect("could not deserialize");
+
+        let mc = MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: "key_generate_mnemonic".to_string(),
+            params,
+            id: Id::Num(0),
+        };
+
+        let config = get_remote_credentials();
+        let mnemonic = key_generate_mnemonic(mc, config).await.unwrap();
+
+        assert_eq!(
+            mnemonic.result,
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        );
+    }
+
+    #[tokio::test]
+    async fn generate_mnemonic_without_params() {
+        let mc = MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: "key_generate_mnemonic".to_string(),
+            params: Params::None,
+            id: Id::Num(0),
+        };
+
+        let config = get_remote_credentials();
+        let mnemonic = key_generate_mnemonic(mc, config).await.unwrap();
+
+        assert_eq!(mnemonic.result.as_str().unwrap().split(' ').count(), 24);
+    }
+
+    #[tokio::test]
+    async fn key_derive_delegated() {
+        let params_str = json!({
+            "mnemonic": "equip will roof matter pink blind book anxiety banner elbow sun young",
+            "path": "m/44'/461'/0/0/0",
+            "language_code": "en",
+            "delegated": true
+        });
+        let params: Params =
+            serde_json::from_str(&params_str.to_string()).expect("could not deserialize");
+
+        let mc = MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: "key_derive".to_string(),
+            params,
+            id: Id::Num(0),
+        };
+
+        let config = get_remote_credentials();
+        let key = key_derive(mc, config).await.unwrap();
+
+        let eth_address = key.result["eth_address"].as_str().unwrap();
+        let delegated_address = key.result["delegated_address"].as_str().unwrap();
+
+        assert!(delegated_address.starts_with("f410f"));
+        assert_eq!(
+            filecoin_signer::delegated_to_eth_address(delegated_address).unwrap(),
+            eth_address
+        );
+    }
+
+    #[tokio::test]
+    async fn inspect_and_convert_address() {
+        let address = "f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba";
+
+        let params: Params =
+            serde_json::from_value(json!({ "address": address })).expect("could not deserialize");
+        let mc = MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: "address_inspect".to_string(),
+            params,
+            id: Id::Num(0),
+        };
+
+        let config = get_remote_credentials();
+        let info = address_inspect(mc, config).await.unwrap();
+
+        assert_eq!(info.result["protocol"], "secp256k1");
+        assert_eq!(info.result["checksum_valid"], true);
+
+        let params: Params =
+            serde_json::from_value(json!({ "address": address, "format": "testnet" }))
+                .expect("could not deserialize");
+        let mc = MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: "address_convert".to_string(),
+            params,
+            id: Id::Num(0),
+        };
+
+        let config = get_remote_credentials();
+        let converted = address_convert(mc, config).await.unwrap();
+
+        assert_eq!(
+            converted.result,
+            "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_and_verify_bytes() {
+        let address = "f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba";
+        let data_hex = hex::encode("filecoin");
+
+        let params: Params = serde_json::from_value(json!({
+            "data_hex": data_hex,
+            "address": address,
+            "prvkey_base64": "8VcW07ADswS4BV2cxi5rnIadVsyTDDhY1NfDH19T8Uo=",
+            "prefixed": true,
+        }))
+        .expect("could not deserialize");
+        let mc = MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: "sign_bytes".to_string(),
+            params,
+            id: Id::Num(0),
+        };
+
+        let config = get_remote_credentials();
+        let signature = sign_bytes(mc, config).await.unwrap();
+
+        assert_eq!(signature.result["type"], 1);
+
+        let signature_data = base64::decode(signature.result["data"].as_str().unwrap()).unwrap();
+        let params: Params = serde_json::from_value(json!({
+            "signature_hex": hex::encode(signature_data),
+            "address": address,
+            "data_hex": data_hex,
+            "prefixed": true,
+        }))
+        .expect("could not deserialize");
+        let mc = MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: "verify_bytes".to_string(),
+            params,
+            id: Id::Num(0),
+        };
+
+        let config = get_remote_credentials();
+        let valid = verify_bytes(mc, config).await.unwrap();
+
+        assert_eq!(valid.result, true);
+    }
+
+    #[tokio::test]
+    async fn verify_mainnet_signed_message() {
+        let private_key =
+            PrivateKey::try_from("8VcW07ADswS4BV2cxi5rnIadVsyTDDhY1NfDH19T8Uo=".to_string())
+                .unwrap();
+        let message: UnsignedMessageAPI = serde_json::from_value(json!({
+            "to": "f17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy",
+            "from": "f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba",
+            "nonce": 1,
+            "value": "100000",
+            "gaslimit": 25000,
+            "gasfeecap": "2500",
+            "gaspremium": "2500",
+            "method": 0,
+            "params": ""
+        }))
+        .expect("could not deserialize");
+        let signed_message = filecoin_signer::transaction_sign(&message, &private_key).unwrap();
+
+        let params: Params = serde_json::from_value(serde_json::to_value(&signed_message).unwrap())
+            .expect("could not deserialize");
+        let mc = MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: "verify_signed_message".to_string(),
+            params,
+            id: Id::Num(0),
+        };
+
+        let config = get_remote_credentials();
+        let valid = verify_signed_message(mc, config).await.unwrap();
+
+        assert_eq!(valid.result, true);
+    }
+    #[tokio::test]
+    async fn aggregate_bls_signed_messages() {
+        let private_key =
+            PrivateKey::try_from("P2pSgkvsZSgi0LOczuHmSXT1+l/hvSs3fVBb4y8OgVo=".to_string())
+                .unwrap();
+        let from = filecoin_signer::key_recover_bls(&private_key, true)
+            .unwrap()
+            .address;
+
+        let signed_messages: Vec<_> = (0..2)
+            .map(|nonce| {
+                let message: UnsignedMessageAPI = serde_json::from_value(json!({
+                    "to": "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy",
+                    "from": from,
+                    "nonce": nonce,
+                    "value": "100000",
+                    "gaslimit": 25000,
+                    "gasfeecap": "2500",
+                    "gaspremium": "2500",
+                    "method": 0,
+                    "params": ""
+                }))
+                .expect("could not deserialize");
+
+                filecoin_signer::transaction_sign(&message, &private_key).unwrap()
+            })
+            .collect();
+
+        let params: Params = serde_json::from_value(json!({ "signed_messages": signed_messages }))
+            .expect("could not deserialize");
+        let mc = MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: "aggregate_bls_messages".to_string(),
+            params,
+            id: Id::Num(0),
+        };
+
+        let config = get_remote_credentials();
+        let aggregate = aggregate_bls_messages(mc, config).await.unwrap();
+
+        assert_eq!(aggregate.result["signature"]["type"], 2);
+        assert_eq!(aggregate.result["cids"].as_array().unwrap().len(), 2);
+    }
+    #[tokio::test]
+    async fn sign_and_verify_transaction_batch() {
+        let transactions: Vec<_> = (0..3)
+            .map(|nonce| {
+                json!({
+                    "to": "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy",
+                    "from": "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba",
+                    "nonce": nonce,
+                    "value": "100000",
+                    "gaslimit": 25000,
+                    "gasfeecap": "2500",
+                    "gaspremium": "2500",
+                    "method": 0,
+                    "params": ""
+                })
+            })
+            .collect();
+
+        let params: Params = serde_json::from_value(json!({
+            "transactions": transactions,
+            "prvkey_base64": "8VcW07ADswS4BV2cxi5rnIadVsyTDDhY1NfDH19T8Uo="
+        }))
+        .expect("could not deserialize");
+        let mc = MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: "sign_transaction_batch".to_string(),
+            params,
+            id: Id::Num(0),
+        };
+
+        let config = get_remote_credentials();
+        let signed = sign_transaction_batch(mc, config).await.unwrap();
+
+        let signatures: Vec<_> = signed
+            .result
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|result| {
+                let signed_message: SignedMessageAPI =
+                    serde_json::from_value(result["ok"].clone()).unwrap();
+                let cbor = filecoin_signer::transaction_serialize(&signed_message.message).unwrap();
+
+                json!({
+                    "signature_hex": hex::encode(&signed_message.signature.data),
+                    "message_hex": hex::encode(cbor.as_ref()),
+                })
+            })
+            .collect();
+        assert_eq!(signatures.len(), 3);
+
+        let params: Params = serde_json::from_value(json!({ "signatures": signatures }))
+            .expect("could not deserialize");
+        let mc = MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: "verify_signature_batch".to_string(),
+            params,
+            id: Id::Num(0),
+        };
+
+        let config = get_remote_credentials();
+        let valid = verify_signature_batch(mc, config).await.unwrap();
+
+        assert_eq!(
+            valid.result,
+            json!([{ "ok": true }, { "ok": true }, { "ok": true }])
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_and_sign_strict() {
+        let transaction = json!({
+            "to": "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy",
+            "from": "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba",
+            "nonce": 1,
+            "value": "-100000",
+            "gaslimit": 25000,
+            "gasfeecap": "2500",
+            "gaspremium": "2500",
+            "method": 0,
+            "params": ""
+        });
+
+        let params: Params = serde_json::from_value(json!({ "transaction": transaction }))
+            .expect("could not deserialize");
+        let mc = MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: "message_validate".to_string(),
+            params,
+            id: Id::Num(0),
+        };
+
+        let config = get_remote_credentials();
+        let validation = message_validate(mc, config).await.unwrap();
+
+        assert_eq!(validation.result["errors"][0]["field"], "value");
+        assert_eq!(validation.result["errors"][0]["kind"], "negative_amount");
+        assert_eq!(validation.result["warnings"], json!([]));
+
+        let sign_call = |strict: bool| {
+            let params: Params = serde_json::from_value(json!({
+                "transaction": transaction,
+                "prvkey_base64": "8VcW07ADswS4BV2cxi5rnIadVsyTDDhY1NfDH19T8Uo=",
+                "strict": strict
+            }))
+            .expect("could not deserialize");
+
+            MethodCall {
+                jsonrpc: Some(Version::V2),
+                method: "sign_transaction".to_string(),
+                params,
+                id: Id::Num(0),
+            }
+        };
+
+        let config = get_remote_credentials();
+        assert!(sign_transaction(sign_call(false), config).await.is_ok());
+
+        let config = get_remote_credentials();
+        assert!(sign_transaction(sign_call(true), config).await.is_err());
+    }
 }
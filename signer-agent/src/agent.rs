@@ -0,0 +1,443 @@
+use std::convert::TryFrom;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::Path;
+use std::sync::Arc;
+use std::thread;
+
+use filecoin_signer::api::{SignatureAPI, SignedMessageAPI, UnsignedMessageAPI};
+use filecoin_signer::keystore::{DecryptedKey, Keystore};
+use filecoin_signer::signature::Signature;
+use filecoin_signer::{
+    key_derive, sign_voucher, transaction_sign_raw, verify_signature, CborBuffer, ExtendedKey,
+};
+use log::{info, warn};
+
+use crate::error::AgentError;
+use crate::protocol::{Request, Response};
+
+/// Keys served by the agent
+///
+/// Keys are looked up regardless of the network, a key loaded as `f1...` signs for `t1...`.
+#[derive(Default)]
+pub struct Agent {
+    keys: Vec<ExtendedKey>,
+}
+
+impl Agent {
+    pub fn new() -> Self {
+        Agent::default()
+    }
+
+    /// Load a key, a key already loaded (on any network) is ignored
+    pub fn add_key(&mut self, extended_key: ExtendedKey) {
+        if self.find_key(&extended_key.address).is_none() {
+            info!("Loaded {}", extended_key.address);
+            self.keys.push(extended_key);
+        }
+    }
+
+    /// Load the keys derived from a mnemonic
+    ///
+    /// # Arguments
+    ///
+    /// * `mnemonic` - A string containing a 24-words English mnemonic
+    /// * `password` - Password to decrypt seed, if none use and empty string (e.g "")
+    /// * `language_code` - The language code for the mnemonic (e.g "en")
+    /// * `paths` - The derivation paths of the keys to load (the path sets the network)
+    ///
+    pub fn load_mnemonic(
+        &mut self,
+        mnemonic: &str,
+        password: &str,
+        language_code: &str,
+        paths: &[String],
+    ) -> Result<(), AgentError> {
+        for path in paths {
+            self.add_key(key_derive(mnemonic, path, password, language_code)?);
+        }
+
+        Ok(())
+    }
+
+    /// Load every document of a keystore, mnemonics are derived along `paths`
+    ///
+    /// # Arguments
+    ///
+    /// * `keystore` - The keystore to load
+    /// * `passphrase` - Passphrase of the keystore documents
+    /// * `password` - Password to decrypt the seed of mnemonics, if none use and empty string (e.g "")
+    /// * `language_code` - The language code for the mnemonics (e.g "en")
+    /// * `paths` - The derivation paths of the keys to load from mnemonics
+    ///
+    pub fn load_keystore(
+        &mut self,
+        keystore: &Keystore,
+        passphrase: &str,
+        password: &str,
+        language_code: &str,
+        paths: &[String],
+    ) -> Result<(), AgentError> {
+        for encrypted_key in keystore.list()? {
+            match encrypted_key.decrypt(passphrase)? {
+                DecryptedKey::Key(extended_key) => self.add_key(extended_key),
+                DecryptedKey::Mnemonic(mnemonic) => {
+                    self.load_mnemonic(&mnemonic.0, password, language_code, paths)?
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Addresses of the loaded keys
+    pub fn addresses(&self) -> Vec<String> {
+        self.keys.iter().map(|key| key.address.clone()).collect()
+    }
+
+    fn find_key(&self, address: &str) -> Option<&ExtendedKey> {
+        let unprefixed = address.get(1..)?;
+
+        self.keys
+            .iter()
+            .find(|key| key.address.get(1..) == Some(unprefixed))
+    }
+
+    fn loaded_key(&self, address: &str) -> Result<&ExtendedKey, AgentError> {
+        self.find_key(address)
+            .ok_or_else(|| AgentError::Agent(format!("No key loaded for {}", address)))
+    }
+
+    fn transaction_sign(
+        &self,
+        unsigned_message: UnsignedMessageAPI,
+    ) -> Result<SignedMessageAPI, AgentError> {
+        let extended_key = self.loaded_key(&unsigned_message.from)?;
+
+        let signature = transaction_sign_raw(&unsigned_message, &extended_key.private_key)?;
+
+        Ok(SignedMessageAPI {
+            message: unsigned_message,
+            signature: SignatureAPI::from(&signature),
+        })
+    }
+
+    fn sign_voucher(&self, voucher: String, address: &str) -> Result<String, AgentError> {
+        let extended_key = self.loaded_key(address)?;
+
+        if extended_key.address.get(1..2) != Some("1") {
+            return Err(AgentError::Agent(
+                "Vouchers are signed with secp256k1 keys only".to_string(),
+            ));
+        }
+
+        Ok(sign_voucher(voucher, &extended_key.private_key)?)
+    }
+
+    fn verify_signature(&self, signature: String, cbor: &str) -> Result<bool, AgentError> {
+        let signature = Signature::try_from(signature)?;
+        let cbor_buffer = CborBuffer(hex::decode(cbor)?);
+
+        Ok(verify_signature(&signature, &cbor_buffer)?)
+    }
+
+    /// Answer a request, failures are reported as `Response::Error`
+    pub fn handle(&self, request: Request) -> Response {
+        let response = match request {
+            Request::ListAddresses => Ok(Response::Addresses(self.addresses())),
+            Request::TransactionSign(unsigned_message) => self
+                .transaction_sign(unsigned_message)
+                .map(Response::SignedMessage),
+            Request::SignVoucher { voucher, address } => self
+                .sign_voucher(voucher, &address)
+                .map(Response::SignedVoucher),
+            Request::VerifySignature { signature, cbor } => {
+                self.verify_signature(signature, &cbor).map(Response::Valid)
+            }
+        };
+
+        response.unwrap_or_else(|err| Response::Error(err.to_string()))
+    }
+
+    /// Serve the requests of a connection until it is closed
+    pub fn handle_connection(&self, stream: UnixStream) -> Result<(), AgentError> {
+        let mut writer = stream.try_clone()?;
+        let reader = BufReader::new(stream);
+
+        for line in reader.lines() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let response = match serde_json::from_str::<Request>(&line) {
+                Ok(request) => self.handle(request),
+                Err(err) => Response::Error(format!("Invalid request: {}", err)),
+            };
+
+            serde_json::to_writer(&mut writer, &response)?;
+            writer.write_all(b"\n")?;
+            writer.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Accept connections forever, each connection is served by its own thread
+    pub fn serve(self: Arc<Self>, listener: UnixListener) -> Result<(), AgentError> {
+        for stream in listener.incoming() {
+            let stream = stream?;
+            let agent = Arc::clone(&self);
+
+            thread::spawn(move || {
+                if let Err(err) = agent.handle_connection(stream) {
+                    warn!("Connection closed: {}", err);
+                }
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Bind the agent socket, only the owner of the process can connect to it
+///
+/// The socket lives in a directory only its owner can access (e.g `$XDG_RUNTIME_DIR`), a missing
+/// directory is created with mode 0700. A socket left at `path` by a previous agent is removed,
+/// any other file is kept and fails the bind.
+pub fn bind<P: AsRef<Path>>(path: P) -> Result<UnixListener, AgentError> {
+    let path = path.as_ref();
+
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    ensure_private_dir(dir)?;
+
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => fs::remove_file(path)?,
+        Ok(_) => {
+            return Err(AgentError::Agent(format!(
+                "{} exists and is not a socket",
+                path.display()
+            )));
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    let listener = UnixListener::bind(path)?;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+
+    Ok(listener)
+}
+
+fn ensure_private_dir(dir: &Path) -> Result<(), AgentError> {
+    if !dir.exists() {
+        fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(dir)?;
+    }
+
+    let metadata = fs::metadata(dir)?;
+    if !metadata.is_dir() || metadata.permissions().mode() & 0o077 != 0 {
+        return Err(AgentError::Agent(format!(
+            "The socket directory {} must only be accessible by its owner (mode 0700)",
+            dir.display()
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::sync::Arc;
+    use std::thread;
+
+    use filecoin_signer::api::UnsignedMessageAPI;
+    use filecoin_signer::keystore::{EncryptedKey, Keystore, KeystoreOptions};
+    use filecoin_signer::{
+        create_voucher, key_derive, key_recover, sign_voucher, transaction_serialize, Mnemonic,
+        PrivateKey,
+    };
+
+    use crate::agent::{bind, Agent};
+    use crate::client::AgentClient;
+    use crate::protocol::{Request, Response};
+
+    const PRIVATE_KEY: &str = "8VcW07ADswS4BV2cxi5rnIadVsyTDDhY1NfDH19T8Uo=";
+    const MNEMONIC: &str = "equip will roof matter pink blind book anxiety banner elbow sun young";
+
+    fn message(from: &str) -> UnsignedMessageAPI {
+        UnsignedMessageAPI {
+            to: "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy".to_string(),
+            from: from.to_string(),
+            nonce: 1,
+            value: "100000".to_string(),
+            gas_limit: 25000,
+            gas_fee_cap: "2500".to_string(),
+            gas_premium: "2500".to_string(),
+            method: 0,
+            params: "".to_string(),
+        }
+    }
+
+    fn agent() -> Agent {
+        let mut agent = Agent::new();
+        let private_key = PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap();
+        agent.add_key(key_recover(&private_key, false).unwrap());
+
+        agent
+    }
+
+    #[test]
+    fn load_keys() {
+        let mut agent = agent();
+
+        // Same key on testnet
+        let private_key = PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap();
+        agent.add_key(key_recover(&private_key, true).unwrap());
+        assert_eq!(agent.addresses().len(), 1);
+
+        agent
+            .load_mnemonic(
+                MNEMONIC,
+                "",
+                "en",
+                &["m/44'/1'/0/0/0".to_string(), "m/44'/1'/0/0/1".to_string()],
+            )
+            .unwrap();
+        assert_eq!(agent.addresses().len(), 3);
+        assert!(agent.addresses()[1].starts_with("t1"));
+    }
+
+    #[test]
+    fn load_keystore_mnemonic() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = Keystore::open(dir.path()).unwrap();
+
+        let options = KeystoreOptions {
+            log_n: 10,
+            ..KeystoreOptions::default()
+        };
+        let encrypted_mnemonic =
+            EncryptedKey::encrypt_mnemonic(&Mnemonic(MNEMONIC.to_string()), "passphrase", &options)
+                .unwrap();
+        keystore.store(&encrypted_mnemonic).unwrap();
+
+        let paths = ["m/44'/1'/0/0/0".to_string()];
+        let mut agent = Agent::new();
+        agent
+            .load_keystore(&keystore, "passphrase", "password", "en", &paths)
+            .unwrap();
+
+        // The mnemonic is derived with the BIP39 password, not the keystore passphrase
+        let expected = key_derive(MNEMONIC, &paths[0], "password", "en").unwrap();
+        assert_eq!(agent.addresses(), vec![expected.address]);
+    }
+
+    #[test]
+    fn sign_only_loaded_keys() {
+        let agent = agent();
+        let address = agent.addresses()[0].clone();
+        let testnet_address = format!("t{}", &address[1..]);
+
+        match agent.handle(Request::TransactionSign(message(&testnet_address))) {
+            Response::SignedMessage(signed_message) => {
+                assert_eq!(signed_message.message.from, testnet_address)
+            }
+            response => panic!("unexpected response {:?}", response),
+        }
+
+        match agent.handle(Request::TransactionSign(message(
+            "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba",
+        ))) {
+            Response::Error(err) => assert!(err.contains("No key loaded")),
+            response => panic!("unexpected response {:?}", response),
+        }
+    }
+
+    #[test]
+    fn bind_in_private_directory() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+
+        // A missing directory is created private
+        let socket = dir.path().join("agent").join("agent.sock");
+        drop(bind(&socket).unwrap());
+        let mode = std::fs::metadata(dir.path().join("agent"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o700);
+
+        // The socket of a previous agent is replaced
+        drop(bind(&socket).unwrap());
+
+        // Other files are never removed
+        let file = dir.path().join("agent").join("not-a-socket");
+        std::fs::write(&file, b"keep me").unwrap();
+        assert!(bind(&file).is_err());
+        assert_eq!(std::fs::read(&file).unwrap(), b"keep me");
+
+        // Nor is a socket bound in a directory other users can access
+        let shared = dir.path().join("shared");
+        std::fs::create_dir(&shared).unwrap();
+        std::fs::set_permissions(&shared, std::fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(bind(shared.join("agent.sock")).is_err());
+    }
+
+    #[test]
+    fn serve_over_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("agent").join("agent.sock");
+
+        let listener = bind(&socket).unwrap();
+        let agent = Arc::new(agent());
+        let address = agent.addresses()[0].clone();
+        thread::spawn(move || agent.serve(listener));
+
+        let mut client = AgentClient::connect(&socket).unwrap();
+        assert_eq!(client.list_addresses().unwrap(), vec![address.clone()]);
+
+        let unsigned_message = message(&address);
+        let signed_message = client.transaction_sign(&unsigned_message).unwrap();
+
+        let cbor = transaction_serialize(&unsigned_message).unwrap();
+        let signature = hex::encode(&signed_message.signature.data);
+        assert!(client
+            .verify_signature(&signature, &hex::encode(cbor.as_ref()))
+            .unwrap());
+
+        assert!(client
+            .transaction_sign(&message("t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba"))
+            .is_err());
+
+        let voucher = create_voucher(
+            "t24acjqhdetck7irsvmn2p6jpuwnouzjxuoa22rva".to_string(),
+            0,
+            0,
+            "10000".to_string(),
+            0,
+            1,
+            0,
+        )
+        .unwrap();
+        let private_key = PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap();
+        assert_eq!(
+            client.sign_voucher(voucher.clone(), &address).unwrap(),
+            sign_voucher(voucher, &private_key).unwrap()
+        );
+
+        // A second client is served concurrently
+        let mut other_client = AgentClient::connect(&socket).unwrap();
+        assert_eq!(other_client.list_addresses().unwrap(), vec![address]);
+    }
+}
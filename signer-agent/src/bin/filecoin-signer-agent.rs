@@ -0,0 +1,133 @@
+//! Signing agent serving filecoin keys over a Unix socket
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::sync::Arc;
+
+use filecoin_signer::keystore::Keystore;
+use filecoin_signer::Mnemonic;
+use gumdrop::Options;
+use log::{error, info};
+
+use filecoin_signer_agent::agent::{bind, Agent};
+use filecoin_signer_agent::error::AgentError;
+
+/// Name of the default socket, in a private directory of `$XDG_RUNTIME_DIR` or of the home
+const SOCKET_NAME: &str = "agent.sock";
+const DEFAULT_PATH: &str = "m/44'/461'/0/0/0";
+/// Passphrase of the keystore documents
+const PASSPHRASE_ENV: &str = "FILECOIN_AGENT_PASSPHRASE";
+/// BIP39 password of the mnemonics (mnemonic file and keystore documents)
+const MNEMONIC_PASSWORD_ENV: &str = "FILECOIN_AGENT_MNEMONIC_PASSWORD";
+
+#[derive(Debug, Options)]
+struct AgentOptions {
+    #[options(help = "print help message")]
+    help: bool,
+
+    #[options(
+        help = "path of the unix socket (default: $XDG_RUNTIME_DIR/filecoin-signer-agent/agent.sock)",
+        meta = "PATH"
+    )]
+    socket: Option<PathBuf>,
+
+    #[options(
+        help = "keystore directory to load, the passphrase is read from FILECOIN_AGENT_PASSPHRASE",
+        meta = "DIR"
+    )]
+    keystore: Option<PathBuf>,
+
+    #[options(help = "file holding a mnemonic to load", meta = "FILE")]
+    mnemonic_file: Option<PathBuf>,
+
+    #[options(help = "language code of the mnemonics (default: en)", meta = "CODE")]
+    language: Option<String>,
+
+    #[options(
+        help = "derivation path of the keys loaded from mnemonics, repeatable (default: m/44'/461'/0/0/0)",
+        meta = "PATH"
+    )]
+    path: Vec<String>,
+}
+
+fn load_agent(options: &AgentOptions) -> Result<Agent, AgentError> {
+    let mut agent = Agent::new();
+
+    let paths = if options.path.is_empty() {
+        vec![DEFAULT_PATH.to_string()]
+    } else {
+        options.path.clone()
+    };
+
+    let password = env::var(MNEMONIC_PASSWORD_ENV).unwrap_or_default();
+    let language = options.language.as_deref().unwrap_or("en");
+
+    if let Some(mnemonic_file) = &options.mnemonic_file {
+        let mnemonic = Mnemonic(fs::read_to_string(mnemonic_file)?);
+
+        agent.load_mnemonic(mnemonic.0.trim(), &password, language, &paths)?;
+    }
+
+    if let Some(keystore) = &options.keystore {
+        let passphrase = env::var(PASSPHRASE_ENV).map_err(|_| {
+            AgentError::Agent(format!("{} is required to load a keystore", PASSPHRASE_ENV))
+        })?;
+
+        agent.load_keystore(
+            &Keystore::open(keystore)?,
+            &passphrase,
+            &password,
+            language,
+            &paths,
+        )?;
+    }
+
+    if agent.addresses().is_empty() {
+        return Err(AgentError::Agent(
+            "No key loaded, use --mnemonic-file or --keystore".to_string(),
+        ));
+    }
+
+    Ok(agent)
+}
+
+fn default_socket() -> Result<PathBuf, AgentError> {
+    let dir = match (env::var_os("XDG_RUNTIME_DIR"), env::var_os("HOME")) {
+        (Some(runtime_dir), _) => PathBuf::from(runtime_dir).join("filecoin-signer-agent"),
+        (None, Some(home)) => PathBuf::from(home).join(".filecoin-signer-agent"),
+        (None, None) => {
+            return Err(AgentError::Agent(
+                "Neither XDG_RUNTIME_DIR nor HOME is set, use --socket".to_string(),
+            ));
+        }
+    };
+
+    Ok(dir.join(SOCKET_NAME))
+}
+
+fn run(options: AgentOptions) -> Result<(), AgentError> {
+    let agent = load_agent(&options)?;
+
+    let socket = match options.socket {
+        Some(socket) => socket,
+        None => default_socket()?,
+    };
+    let listener = bind(&socket)?;
+    info!("Listening on {}", socket.display());
+
+    Arc::new(agent).serve(listener)
+}
+
+fn main() {
+    env_logger::init();
+
+    let options = AgentOptions::parse_args_default_or_exit();
+
+    if let Err(err) = run(options) {
+        error!("{}", err);
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
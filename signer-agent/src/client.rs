@@ -0,0 +1,113 @@
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+
+use filecoin_signer::api::{SignedMessageAPI, UnsignedMessageAPI};
+
+use crate::error::AgentError;
+use crate::protocol::{Request, Response};
+
+/// Connection to a signing agent
+pub struct AgentClient {
+    reader: BufReader<UnixStream>,
+    writer: UnixStream,
+}
+
+impl AgentClient {
+    /// Connect to the agent listening on `path`
+    pub fn connect<P: AsRef<Path>>(path: P) -> Result<Self, AgentError> {
+        let writer = UnixStream::connect(path)?;
+        let reader = BufReader::new(writer.try_clone()?);
+
+        Ok(AgentClient { reader, writer })
+    }
+
+    /// Send a request and wait for the answer, `Response::Error` is returned as is
+    pub fn request(&mut self, request: &Request) -> Result<Response, AgentError> {
+        serde_json::to_writer(&mut self.writer, request)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()?;
+
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(AgentError::Agent(
+                "Connection closed by the agent".to_string(),
+            ));
+        }
+
+        Ok(serde_json::from_str(&line)?)
+    }
+
+    fn call(&mut self, request: &Request) -> Result<Response, AgentError> {
+        match self.request(request)? {
+            Response::Error(err) => Err(AgentError::Agent(err)),
+            response => Ok(response),
+        }
+    }
+
+    /// Addresses of the keys loaded in the agent
+    pub fn list_addresses(&mut self) -> Result<Vec<String>, AgentError> {
+        match self.call(&Request::ListAddresses)? {
+            Response::Addresses(addresses) => Ok(addresses),
+            response => Err(unexpected(response)),
+        }
+    }
+
+    /// Sign a message with the agent key of its `from` address
+    ///
+    /// # Arguments
+    ///
+    /// * `unsigned_message` - an unsigned filecoin message
+    ///
+    pub fn transaction_sign(
+        &mut self,
+        unsigned_message: &UnsignedMessageAPI,
+    ) -> Result<SignedMessageAPI, AgentError> {
+        match self.call(&Request::TransactionSign(unsigned_message.clone()))? {
+            Response::SignedMessage(signed_message) => Ok(signed_message),
+            response => Err(unexpected(response)),
+        }
+    }
+
+    /// Sign a payment channel voucher
+    ///
+    /// # Arguments
+    ///
+    /// * `voucher` - Voucher as base64 string
+    /// * `address` - Address of the secp256k1 agent key signing the voucher
+    ///
+    pub fn sign_voucher(&mut self, voucher: String, address: &str) -> Result<String, AgentError> {
+        let request = Request::SignVoucher {
+            voucher,
+            address: address.to_string(),
+        };
+
+        match self.call(&request)? {
+            Response::SignedVoucher(signed_voucher) => Ok(signed_voucher),
+            response => Err(unexpected(response)),
+        }
+    }
+
+    /// Verify a signature
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - RSV format signature or BLS signature as hex string
+    /// * `cbor` - the CBOR transaction as hex string
+    ///
+    pub fn verify_signature(&mut self, signature: &str, cbor: &str) -> Result<bool, AgentError> {
+        let request = Request::VerifySignature {
+            signature: signature.to_string(),
+            cbor: cbor.to_string(),
+        };
+
+        match self.call(&request)? {
+            Response::Valid(valid) => Ok(valid),
+            response => Err(unexpected(response)),
+        }
+    }
+}
+
+fn unexpected(response: Response) -> AgentError {
+    AgentError::Agent(format!("Unexpected response {:?}", response))
+}
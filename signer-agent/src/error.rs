@@ -0,0 +1,22 @@
+use filecoin_signer::error::SignerError;
+use thiserror::Error;
+
+/// Signing agent error
+#[derive(Error, Debug)]
+pub enum AgentError {
+    /// Signer error
+    #[error("Signer error | {0}")]
+    Signer(#[from] SignerError),
+    /// IO error
+    #[error("IO error | {0}")]
+    IO(#[from] std::io::Error),
+    /// JSON error
+    #[error("JSON error | {0}")]
+    JSON(#[from] serde_json::Error),
+    /// Hex Error
+    #[error("Hex decoding error | {0}")]
+    HexDecode(#[from] hex::FromHexError),
+    /// Error reported by the agent
+    #[error("Agent error: `{0}`")]
+    Agent(String),
+}
@@ -0,0 +1,9 @@
+//! Signing agent keeping filecoin keys in a dedicated process
+//!
+//! The agent serves newline delimited JSON requests (`protocol::Request`) over a Unix socket,
+//! `client::AgentClient` is the matching client.
+
+pub mod agent;
+pub mod client;
+pub mod error;
+pub mod protocol;
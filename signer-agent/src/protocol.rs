@@ -0,0 +1,29 @@
+use filecoin_signer::api::{SignedMessageAPI, UnsignedMessageAPI};
+use serde::{Deserialize, Serialize};
+
+/// Request sent to the agent, one JSON document per line
+///
+/// e.g `{"method":"transaction_sign","params":{"to":"t1...","from":"t1...",...}}`
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+pub enum Request {
+    /// Addresses of the loaded keys
+    ListAddresses,
+    /// Sign a message, its `from` must be a loaded key
+    TransactionSign(UnsignedMessageAPI),
+    /// Sign a payment channel voucher (base64) with the secp256k1 key of `address`
+    SignVoucher { voucher: String, address: String },
+    /// Verify a signature (hex) against a CBOR message (hex)
+    VerifySignature { signature: String, cbor: String },
+}
+
+/// Answer of the agent, one JSON document per line
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Response {
+    Addresses(Vec<String>),
+    SignedMessage(SignedMessageAPI),
+    SignedVoucher(String),
+    Valid(bool),
+    Error(String),
+}
@@ -32,6 +32,14 @@ class Filecoin {
     filecoin
       .lookup<NativeFunction<Pointer<Utf8> Function(Pointer, Pointer)>>("filecoin_signer_extended_key_public_key")
       .asFunction();
+  static final Pointer<Utf8> Function(Pointer, Pointer) extendedKeyEthAddress =
+    filecoin
+      .lookup<NativeFunction<Pointer<Utf8> Function(Pointer, Pointer)>>("filecoin_signer_extended_key_eth_address")
+      .asFunction();
+  static final Pointer<Utf8> Function(Pointer, Pointer) extendedKeyDelegatedAddress =
+    filecoin
+      .lookup<NativeFunction<Pointer<Utf8> Function(Pointer, Pointer)>>("filecoin_signer_extended_key_delegated_address")
+      .asFunction();
   static final void Function(Pointer) extendedKeyFree =
     filecoin
       .lookup<NativeFunction<Void Function(Pointer)>>("filecoin_signer_extended_key_free")
@@ -42,9 +50,63 @@ class Filecoin {
       .lookup<NativeFunction<Pointer Function(Pointer<Utf8>, Pointer<Utf8>, Pointer)>>("filecoin_signer_key_derive")
       .asFunction();
 
+  static final Pointer Function(Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>, Pointer) keyDeriveBls =
+    filecoin
+      .lookup<NativeFunction<Pointer Function(Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>, Pointer)>>("filecoin_signer_key_derive_bls")
+      .asFunction();
+  static final Pointer Function(int, Pointer) keyGenerateBls =
+    filecoin
+      .lookup<NativeFunction<Pointer Function(Uint8, Pointer)>>("filecoin_signer_key_generate_bls")
+      .asFunction();
+
+  static final Pointer Function(Pointer<Utf8>, int, Pointer) keyImportLotus =
+    filecoin
+      .lookup<NativeFunction<Pointer Function(Pointer<Utf8>, Uint8, Pointer)>>("filecoin_signer_key_import_lotus")
+      .asFunction();
+  static final Pointer<Utf8> Function(Pointer, Pointer) keyExportLotus =
+    filecoin
+      .lookup<NativeFunction<Pointer<Utf8> Function(Pointer, Pointer)>>("filecoin_signer_key_export_lotus")
+      .asFunction();
+
+  static final Pointer<Utf8> Function(Pointer<Utf8>, Pointer) addressInspect =
+    filecoin
+      .lookup<NativeFunction<Pointer<Utf8> Function(Pointer<Utf8>, Pointer)>>("filecoin_signer_address_inspect")
+      .asFunction();
+  static final Pointer<Utf8> Function(Pointer<Utf8>, Pointer<Utf8>, Pointer) addressConvert =
+    filecoin
+      .lookup<NativeFunction<Pointer<Utf8> Function(Pointer<Utf8>, Pointer<Utf8>, Pointer)>>("filecoin_signer_address_convert")
+      .asFunction();
+
+  static final Pointer<Utf8> Function(Pointer<Utf8>, Pointer) aggregateBlsSignatures =
+    filecoin
+      .lookup<NativeFunction<Pointer<Utf8> Function(Pointer<Utf8>, Pointer)>>("filecoin_signer_aggregate_bls_signatures")
+      .asFunction();
+  static final Pointer<Utf8> Function(Pointer<Utf8>, Pointer) aggregateBlsMessages =
+    filecoin
+      .lookup<NativeFunction<Pointer<Utf8> Function(Pointer<Utf8>, Pointer)>>("filecoin_signer_aggregate_bls_messages")
+      .asFunction();
+
+  static final Pointer<Utf8> Function(Pointer<Utf8>, Pointer<Utf8>, Pointer) transactionSignBatch =
+    filecoin
+      .lookup<NativeFunction<Pointer<Utf8> Function(Pointer<Utf8>, Pointer<Utf8>, Pointer)>>("filecoin_signer_transaction_sign_batch")
+      .asFunction();
+  static final Pointer<Utf8> Function(Pointer<Utf8>, Pointer) verifySignatureBatch =
+    filecoin
+      .lookup<NativeFunction<Pointer<Utf8> Function(Pointer<Utf8>, Pointer)>>("filecoin_signer_verify_signature_batch")
+      .asFunction();
+
+  static final Pointer<Utf8> Function(Pointer<Utf8>, Pointer<Utf8>, Pointer) messageValidate =
+    filecoin
+      .lookup<NativeFunction<Pointer<Utf8> Function(Pointer<Utf8>, Pointer<Utf8>, Pointer)>>("filecoin_signer_message_validate")
+      .asFunction();
+
   static final void Function(Pointer<Utf8>) stringFree =
     filecoin
       .lookup<NativeFunction<Void Function(Pointer<Utf8>)>>("filecoin_signer_string_free")
       .asFunction();
+  static final void Function(Pointer<Utf8>) secretStringFree =
+    filecoin
+      .lookup<NativeFunction<Void Function(Pointer<Utf8>)>>("filecoin_signer_secret_string_free")
+      .asFunction();
 }
 
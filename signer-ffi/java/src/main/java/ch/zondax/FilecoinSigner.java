@@ -8,9 +8,26 @@ public class FilecoinSigner {
 
     public static native String extendedKeyPrivateKey(long ptr, long err);
     public static native String extendedKeyPublicKey(long ptr, long err);
+    public static native String extendedKeyEthAddress(long ptr, long err);
+    public static native String extendedKeyDelegatedAddress(long ptr, long err);
     public static native void extendedKeyFree(long ptr);
 
     public static native long keyDerive(String mnemonic, String path, String password, long err);
+    public static native long keyDeriveBls(String mnemonic, String path, String password, String languageCode, long err);
+    public static native long keyGenerateBls(boolean testnet, long err);
+    public static native long keyImportLotus(String lotusKey, boolean testnet, long err);
+    public static native String keyExportLotus(long ptr, long err);
+
+    public static native String addressInspect(String address, long err);
+    public static native String addressConvert(String address, String format, long err);
+
+    public static native String aggregateBlsSignatures(String signatures, long err);
+    public static native String aggregateBlsMessages(String signedMessages, long err);
+
+    public static native String transactionSignBatch(String unsignedMessages, String privateKey, long err);
+    public static native String verifySignatureBatch(String signatures, long err);
+
+    public static native String messageValidate(String unsignedMessage, String actorType, long err);
 
     static {
         System.loadLibrary("filecoin_signer_ffi");
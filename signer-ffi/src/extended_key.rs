@@ -1,12 +1,15 @@
 use ffi_support::{call_with_result, ExternError};
-use filecoin_signer::ExtendedKey;
+use filecoin_signer::error::SignerError;
+use filecoin_signer::{key_export_lotus, ExtendedKey};
+use zeroize::Zeroizing;
 
 create_fn!(filecoin_signer_extended_key_private_key|Java_ch_zondax_FilecoinSigner_extendedKeyPrivateKey: (
     ek: &mut ExtendedKey,
     error: &mut ExternError
 ) -> str_ret_ty!(), |etc| {
     call_with_result(error, || -> Result<str_ret_ty!(), ExternError> {
-        create_string!(etc, hex::encode(&ek.private_key.0))
+        let private_key = Zeroizing::new(hex::encode(&ek.private_key.0));
+        create_string!(etc, private_key.as_str())
     })
 });
 
@@ -19,6 +22,40 @@ create_fn!(filecoin_signer_extended_key_public_key|Java_ch_zondax_FilecoinSigner
     })
 });
 
+create_fn!(filecoin_signer_extended_key_eth_address|Java_ch_zondax_FilecoinSigner_extendedKeyEthAddress: (
+    ek: &mut ExtendedKey,
+    error: &mut ExternError
+) -> str_ret_ty!(), |etc| {
+    call_with_result(error, || -> Result<str_ret_ty!(), ExternError> {
+        let eth_address = ek.eth_address()?.ok_or_else(|| {
+            SignerError::GenericString("Only secp256k1 keys have an Ethereum address".to_string())
+        })?;
+        create_string!(etc, eth_address)
+    })
+});
+
+create_fn!(filecoin_signer_extended_key_delegated_address|Java_ch_zondax_FilecoinSigner_extendedKeyDelegatedAddress: (
+    ek: &mut ExtendedKey,
+    error: &mut ExternError
+) -> str_ret_ty!(), |etc| {
+    call_with_result(error, || -> Result<str_ret_ty!(), ExternError> {
+        let delegated_address = ek.delegated_address()?.ok_or_else(|| {
+            SignerError::GenericString("Only secp256k1 keys have a delegated address".to_string())
+        })?;
+        create_string!(etc, delegated_address)
+    })
+});
+
+create_fn!(filecoin_signer_key_export_lotus|Java_ch_zondax_FilecoinSigner_keyExportLotus: (
+    ek: &mut ExtendedKey,
+    error: &mut ExternError
+) -> str_ret_ty!(), |etc| {
+    call_with_result(error, || -> Result<str_ret_ty!(), ExternError> {
+        let lotus_key = Zeroizing::new(key_export_lotus(ek)?);
+        create_string!(etc, lotus_key.as_str())
+    })
+});
+
 create_fn_destructor!(
     ExtendedKey,
     filecoin_signer_extended_key_free | Java_ch_zondax_FilecoinSigner_extendedKeyFree
@@ -7,7 +7,17 @@ mod error;
 mod extended_key;
 
 use ffi_support::{call_with_result, ExternError};
-use filecoin_signer::{key_derive, ExtendedKey};
+use filecoin_signer::address::AddressFormat;
+use filecoin_signer::api::{BatchResultAPI, SignedMessageAPI, UnsignedMessageAPI};
+use filecoin_signer::error::SignerError;
+use filecoin_signer::signature::{Signature, SignatureBLS};
+use filecoin_signer::{
+    address_convert, address_inspect, aggregate_bls_messages, aggregate_bls_signatures, key_derive,
+    key_derive_bls, key_generate_bls, key_import_lotus, message_validate, transaction_sign_batch,
+    verify_signature_batch, CborBuffer, ExtendedKey, PrivateKey,
+};
+use std::convert::TryFrom;
+use std::str::FromStr;
 
 create_fn!(filecoin_signer_key_derive|Java_ch_zondax_FilecoinSigner_keyDerive: (
     mnemonic: str_arg_ty!(),
@@ -30,9 +40,195 @@ create_fn!(filecoin_signer_key_derive|Java_ch_zondax_FilecoinSigner_keyDerive: (
     })
 });
 
+create_fn!(filecoin_signer_key_derive_bls|Java_ch_zondax_FilecoinSigner_keyDeriveBls: (
+    mnemonic: str_arg_ty!(),
+    path: str_arg_ty!(),
+    password: str_arg_ty!(),
+    language_code: str_arg_ty!(),
+    error: &mut ExternError
+) -> ptr!(ExtendedKey), |etc| {
+    call_with_result(error, || -> Result<ExtendedKey, ExternError> {
+        let mnemonic = get_string!(etc, mnemonic)?;
+        let path = get_string!(etc, path)?;
+        let password = get_string!(etc, password)?;
+        let language_code = get_string!(etc, language_code)?;
+        Ok(key_derive_bls(
+            get_string_ref(&mnemonic),
+            get_string_ref(&path),
+            get_string_ref(&password),
+            get_string_ref(&language_code),
+        )?)
+    })
+});
+
+create_fn!(filecoin_signer_key_generate_bls|Java_ch_zondax_FilecoinSigner_keyGenerateBls: (
+    testnet: bool_arg_ty!(),
+    error: &mut ExternError
+) -> ptr!(ExtendedKey), |_| {
+    call_with_result(error, || -> Result<ExtendedKey, ExternError> {
+        Ok(key_generate_bls(get_bool!(testnet))?)
+    })
+});
+
+create_fn!(filecoin_signer_key_import_lotus|Java_ch_zondax_FilecoinSigner_keyImportLotus: (
+    lotus_key: str_arg_ty!(),
+    testnet: bool_arg_ty!(),
+    error: &mut ExternError
+) -> ptr!(ExtendedKey), |etc| {
+    call_with_result(error, || -> Result<ExtendedKey, ExternError> {
+        let lotus_key = get_string!(etc, lotus_key)?;
+        Ok(key_import_lotus(get_string_ref(&lotus_key), get_bool!(testnet))?)
+    })
+});
+
+create_fn!(filecoin_signer_address_inspect|Java_ch_zondax_FilecoinSigner_addressInspect: (
+    address: str_arg_ty!(),
+    error: &mut ExternError
+) -> str_ret_ty!(), |etc| {
+    call_with_result(error, || -> Result<str_ret_ty!(), ExternError> {
+        let address = get_string!(etc, address)?;
+        let info = address_inspect(get_string_ref(&address))?;
+        let info_json = serde_json::to_string(&info)
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+        create_string!(etc, info_json)
+    })
+});
+
+create_fn!(filecoin_signer_address_convert|Java_ch_zondax_FilecoinSigner_addressConvert: (
+    address: str_arg_ty!(),
+    format: str_arg_ty!(),
+    error: &mut ExternError
+) -> str_ret_ty!(), |etc| {
+    call_with_result(error, || -> Result<str_ret_ty!(), ExternError> {
+        let address = get_string!(etc, address)?;
+        let format = get_string!(etc, format)?;
+        let format = AddressFormat::from_str(get_string_ref(&format))?;
+        create_string!(etc, address_convert(get_string_ref(&address), format)?)
+    })
+});
+
+create_fn!(filecoin_signer_aggregate_bls_signatures|Java_ch_zondax_FilecoinSigner_aggregateBlsSignatures: (
+    signatures: str_arg_ty!(),
+    error: &mut ExternError
+) -> str_ret_ty!(), |etc| {
+    call_with_result(error, || -> Result<str_ret_ty!(), ExternError> {
+        let signatures = get_string!(etc, signatures)?;
+        let signatures_hex: Vec<String> = serde_json::from_str(get_string_ref(&signatures))
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+        let signatures = signatures_hex
+            .into_iter()
+            .map(SignatureBLS::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let signature = aggregate_bls_signatures(&signatures)?;
+        create_string!(etc, signature.to_string())
+    })
+});
+
+create_fn!(filecoin_signer_aggregate_bls_messages|Java_ch_zondax_FilecoinSigner_aggregateBlsMessages: (
+    signed_messages: str_arg_ty!(),
+    error: &mut ExternError
+) -> str_ret_ty!(), |etc| {
+    call_with_result(error, || -> Result<str_ret_ty!(), ExternError> {
+        let signed_messages = get_string!(etc, signed_messages)?;
+        let signed_messages: Vec<SignedMessageAPI> =
+            serde_json::from_str(get_string_ref(&signed_messages))
+                .map_err(|err| SignerError::GenericString(err.to_string()))?;
+        let aggregate = aggregate_bls_messages(&signed_messages)?;
+        let aggregate_json = serde_json::to_string(&aggregate)
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+        create_string!(etc, aggregate_json)
+    })
+});
+
+create_fn!(filecoin_signer_transaction_sign_batch|Java_ch_zondax_FilecoinSigner_transactionSignBatch: (
+    unsigned_messages: str_arg_ty!(),
+    private_key: str_arg_ty!(),
+    error: &mut ExternError
+) -> str_ret_ty!(), |etc| {
+    call_with_result(error, || -> Result<str_ret_ty!(), ExternError> {
+        let unsigned_messages = get_string!(etc, unsigned_messages)?;
+        let unsigned_messages: Vec<UnsignedMessageAPI> =
+            serde_json::from_str(get_string_ref(&unsigned_messages))
+                .map_err(|err| SignerError::GenericString(err.to_string()))?;
+        let private_key = get_string!(etc, private_key)?;
+        let private_key = PrivateKey::try_from(get_string_ref(&private_key).to_string())?;
+        let results: Vec<BatchResultAPI<SignedMessageAPI>> =
+            transaction_sign_batch(&unsigned_messages, &private_key)
+                .into_iter()
+                .map(BatchResultAPI::from)
+                .collect();
+        let results_json = serde_json::to_string(&results)
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+        create_string!(etc, results_json)
+    })
+});
+
+create_fn!(filecoin_signer_verify_signature_batch|Java_ch_zondax_FilecoinSigner_verifySignatureBatch: (
+    signatures: str_arg_ty!(),
+    error: &mut ExternError
+) -> str_ret_ty!(), |etc| {
+    call_with_result(error, || -> Result<str_ret_ty!(), ExternError> {
+        let signatures = get_string!(etc, signatures)?;
+        let signatures_hex: Vec<(String, String)> =
+            serde_json::from_str(get_string_ref(&signatures))
+                .map_err(|err| SignerError::GenericString(err.to_string()))?;
+        let signatures = signatures_hex
+            .into_iter()
+            .map(|(signature_hex, message_hex)| {
+                let signature = Signature::try_from(signature_hex)?;
+                let message = CborBuffer(hex::decode(message_hex)?);
+                Ok((signature, message))
+            })
+            .collect::<Result<Vec<_>, SignerError>>()?;
+        let results: Vec<BatchResultAPI<bool>> = verify_signature_batch(&signatures)
+            .into_iter()
+            .map(BatchResultAPI::from)
+            .collect();
+        let results_json = serde_json::to_string(&results)
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+        create_string!(etc, results_json)
+    })
+});
+
+create_fn!(filecoin_signer_message_validate|Java_ch_zondax_FilecoinSigner_messageValidate: (
+    unsigned_message: str_arg_ty!(),
+    actor_type: str_arg_ty!(),
+    error: &mut ExternError
+) -> str_ret_ty!(), |etc| {
+    call_with_result(error, || -> Result<str_ret_ty!(), ExternError> {
+        let unsigned_message = get_string!(etc, unsigned_message)?;
+        let unsigned_message: UnsignedMessageAPI =
+            serde_json::from_str(get_string_ref(&unsigned_message))
+                .map_err(|err| SignerError::GenericString(err.to_string()))?;
+        // An empty actor type stands for an unknown one
+        let actor_type = get_string!(etc, actor_type)?;
+        let actor_type =
+            Some(get_string_ref(&actor_type)).filter(|actor_type| !actor_type.is_empty());
+        let validation = message_validate(&unsigned_message, actor_type);
+        let validation_json = serde_json::to_string(&validation)
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+        create_string!(etc, validation_json)
+    })
+});
+
 #[cfg(not(feature = "with-jni"))]
 ffi_support::define_string_destructor!(filecoin_signer_string_free);
 
+/// Free a string holding a secret (e.g a private key) after wiping it
+///
+/// # Safety
+///
+/// `s` must be a string returned by this library and not freed yet.
+#[cfg(not(feature = "with-jni"))]
+#[no_mangle]
+pub unsafe extern "C" fn filecoin_signer_secret_string_free(s: *mut std::os::raw::c_char) {
+    use zeroize::Zeroize;
+
+    if !s.is_null() {
+        std::ffi::CString::from_raw(s).into_bytes().zeroize();
+    }
+}
+
 #[cfg(feature = "with-jni")]
 fn get_string_ref(s: &std::ffi::CStr) -> &str {
     ffi_support::FfiStr::from_cstr(s).as_str()
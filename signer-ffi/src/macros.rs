@@ -58,6 +58,32 @@ macro_rules! str_arg_ty {
 #[cfg(not(feature = "with-jni"))]
 macro_rules! str_arg_ty { () => { ffi_support::FfiStr<'_> } }
 
+#[cfg(feature = "with-jni")]
+macro_rules! bool_arg_ty {
+    () => {
+        jni::sys::jboolean
+    };
+}
+#[cfg(not(feature = "with-jni"))]
+macro_rules! bool_arg_ty {
+    () => {
+        bool
+    };
+}
+
+#[cfg(feature = "with-jni")]
+macro_rules! get_bool {
+    ($e:expr) => {
+        $e != jni::sys::JNI_FALSE
+    };
+}
+#[cfg(not(feature = "with-jni"))]
+macro_rules! get_bool {
+    ($e:expr) => {
+        $e
+    };
+}
+
 macro_rules! str_ret_ty { () => { *mut std::os::raw::c_char } }
 
 macro_rules! create_fn {
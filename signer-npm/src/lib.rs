@@ -1,11 +1,16 @@
 #![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used,))]
 
 use std::convert::TryFrom;
+use std::str::FromStr;
 
 use wasm_bindgen::prelude::*;
+use zeroize::Zeroizing;
 
-use filecoin_signer::api::{MessageParams, MessageTxAPI, UnsignedMessageAPI};
-use filecoin_signer::signature::Signature;
+use filecoin_signer::address::AddressFormat;
+use filecoin_signer::api::{
+    BatchResultAPI, MessageParams, MessageTxAPI, SignatureAPI, SignedMessageAPI, UnsignedMessageAPI,
+};
+use filecoin_signer::signature::{Signature, SignatureBLS};
 use filecoin_signer::{CborBuffer, PrivateKey};
 
 mod utils;
@@ -49,7 +54,7 @@ impl ExtendedKey {
 
     #[wasm_bindgen(getter)]
     pub fn private_hexstring(&self) -> String {
-        hex::encode(&self.private_raw())
+        hex::encode(&self.0.private_key.0)
     }
 
     #[wasm_bindgen(getter)]
@@ -59,13 +64,27 @@ impl ExtendedKey {
 
     #[wasm_bindgen(getter)]
     pub fn private_base64(&self) -> String {
-        base64::encode(&self.private_raw())
+        base64::encode(&self.0.private_key.0)
     }
 
     #[wasm_bindgen(getter)]
     pub fn address(&self) -> String {
         self.0.address.clone()
     }
+
+    #[wasm_bindgen(getter)]
+    pub fn eth_address(&self) -> Result<Option<String>, JsValue> {
+        self.0
+            .eth_address()
+            .map_err(|e| JsValue::from(format!("Error computing Ethereum address: {}", e)))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn delegated_address(&self) -> Result<Option<String>, JsValue> {
+        self.0
+            .delegated_address()
+            .map_err(|e| JsValue::from(format!("Error computing delegated address: {}", e)))
+    }
 }
 
 fn extract_private_key(private_key_js: JsValue) -> Result<PrivateKey, JsValue> {
@@ -106,13 +125,64 @@ fn extract_bytes(encoded_bytes_js: JsValue, error_message: &str) -> Result<Vec<u
 }
 
 #[wasm_bindgen(js_name = generateMnemonic)]
-pub fn mnemonic_generate() -> Result<String, JsValue> {
+pub fn mnemonic_generate(
+    word_count: Option<usize>,
+    language_code: Option<String>,
+    entropy_hexstring: Option<String>,
+) -> Result<String, JsValue> {
     set_panic_hook();
 
-    let mnemonic = filecoin_signer::key_generate_mnemonic()
-        .map_err(|e| JsValue::from(format!("Error generating key: {}", e)))?;
+    let language_code = language_code
+        .unwrap_or_else(|| filecoin_signer::DEFAULT_MNEMONIC_LANGUAGE_CODE.to_string());
+
+    let mnemonic = match entropy_hexstring {
+        Some(entropy_hexstring) => {
+            let entropy = hex::decode(&entropy_hexstring)
+                .map_err(|e| JsValue::from(format!("Error decoding entropy: {}", e)))?;
+
+            let mnemonic =
+                filecoin_signer::key_generate_mnemonic_from_entropy(&entropy, &language_code)
+                    .map_err(|e| JsValue::from(format!("Error generating key: {}", e)))?;
+
+            if let Some(word_count) = word_count {
+                if mnemonic.0.split_whitespace().count() != word_count {
+                    return Err(JsValue::from(
+                        "Error generating key: entropy size does not match word count",
+                    ));
+                }
+            }
+
+            mnemonic
+        }
+        None => filecoin_signer::key_generate_mnemonic_with_options(
+            word_count.unwrap_or(filecoin_signer::DEFAULT_MNEMONIC_WORD_COUNT),
+            &language_code,
+        )
+        .map_err(|e| JsValue::from(format!("Error generating key: {}", e)))?,
+    };
 
-    Ok(mnemonic.0)
+    Ok(mnemonic.0.clone())
+}
+
+#[wasm_bindgen(js_name = mnemonicValidate)]
+pub fn mnemonic_validate(
+    mnemonic: String,
+    password: Option<String>,
+    language_code: Option<String>,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+
+    let mnemonic = Zeroizing::new(mnemonic);
+    let password = Zeroizing::new(password.unwrap_or_default());
+
+    let validation =
+        filecoin_signer::mnemonic_validate(&mnemonic, &password, language_code.as_deref())
+            .map_err(|e| JsValue::from(format!("Error validating mnemonic: {}", e)))?;
+
+    let validation_js =
+        JsValue::from_serde(&validation).map_err(|e| JsValue::from(e.to_string()))?;
+
+    Ok(validation_js)
 }
 
 #[wasm_bindgen(js_name = keyDerive)]
@@ -124,6 +194,9 @@ pub fn key_derive(
 ) -> Result<ExtendedKey, JsValue> {
     set_panic_hook();
 
+    let mnemonic = Zeroizing::new(mnemonic);
+    let password = Zeroizing::new(password);
+
     let lc = match language_code {
         Some(lc) => lc,
         None => "en".to_string(),
@@ -139,7 +212,10 @@ pub fn key_derive(
 pub fn key_derive_from_seed(seed: JsValue, path: String) -> Result<ExtendedKey, JsValue> {
     set_panic_hook();
 
-    let seed_bytes = extract_bytes(seed, "Seed must be a valid hexstring, base64 or a buffer")?;
+    let seed_bytes = Zeroizing::new(extract_bytes(
+        seed,
+        "Seed must be a valid hexstring, base64 or a buffer",
+    )?);
 
     let key_address = filecoin_signer::key_derive_from_seed(&seed_bytes, &path)
         .map_err(|e| JsValue::from(format!("Error deriving key: {}", e)))?;
@@ -159,6 +235,58 @@ pub fn key_recover(private_key_js: JsValue, testnet: bool) -> Result<ExtendedKey
     Ok(ExtendedKey { 0: key_address })
 }
 
+#[wasm_bindgen(js_name = ethAddressToDelegated)]
+pub fn eth_address_to_delegated(eth_address: String, testnet: bool) -> Result<String, JsValue> {
+    set_panic_hook();
+
+    filecoin_signer::eth_address_to_delegated(&eth_address, testnet)
+        .map_err(|e| JsValue::from(format!("Error converting Ethereum address: {}", e)))
+}
+
+#[wasm_bindgen(js_name = delegatedToEthAddress)]
+pub fn delegated_to_eth_address(address: String) -> Result<String, JsValue> {
+    set_panic_hook();
+
+    filecoin_signer::delegated_to_eth_address(&address)
+        .map_err(|e| JsValue::from(format!("Error converting delegated address: {}", e)))
+}
+
+#[wasm_bindgen(js_name = addressInspect)]
+pub fn address_inspect(address: String) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+
+    let info = filecoin_signer::address_inspect(&address)
+        .map_err(|e| JsValue::from(format!("Error inspecting address: {}", e)))?;
+
+    let info_js = JsValue::from_serde(&info).map_err(|e| JsValue::from(e.to_string()))?;
+
+    Ok(info_js)
+}
+
+#[wasm_bindgen(js_name = addressConvert)]
+pub fn address_convert(address: String, format: String) -> Result<String, JsValue> {
+    set_panic_hook();
+
+    let format = AddressFormat::from_str(&format)
+        .map_err(|e| JsValue::from(format!("Error converting address: {}", e)))?;
+
+    filecoin_signer::address_convert(&address, format)
+        .map_err(|e| JsValue::from(format!("Error converting address: {}", e)))
+}
+
+#[wasm_bindgen(js_name = addressFromBytes)]
+pub fn address_from_bytes(address_bytes: JsValue, testnet: bool) -> Result<String, JsValue> {
+    set_panic_hook();
+
+    let bytes = extract_bytes(
+        address_bytes,
+        "Address bytes must be a valid hexstring, base64 or a buffer",
+    )?;
+
+    filecoin_signer::address_from_bytes(&bytes, testnet)
+        .map_err(|e| JsValue::from(format!("Error converting address: {}", e)))
+}
+
 #[wasm_bindgen(js_name = keyRecoverBLS)]
 pub fn key_recover_bls(private_key_js: JsValue, testnet: bool) -> Result<ExtendedKey, JsValue> {
     set_panic_hook();
@@ -171,6 +299,76 @@ pub fn key_recover_bls(private_key_js: JsValue, testnet: bool) -> Result<Extende
     Ok(ExtendedKey { 0: key_address })
 }
 
+#[wasm_bindgen(js_name = keyDeriveBLS)]
+pub fn key_derive_bls(
+    mnemonic: String,
+    path: String,
+    password: String,
+    language_code: Option<String>,
+) -> Result<ExtendedKey, JsValue> {
+    set_panic_hook();
+
+    let mnemonic = Zeroizing::new(mnemonic);
+    let password = Zeroizing::new(password);
+
+    let lc = match language_code {
+        Some(lc) => lc,
+        None => "en".to_string(),
+    };
+
+    let key_address = filecoin_signer::key_derive_bls(&mnemonic, &path, &password, &lc)
+        .map_err(|e| JsValue::from(format!("Error deriving key: {}", e)))?;
+
+    Ok(ExtendedKey { 0: key_address })
+}
+
+#[wasm_bindgen(js_name = keyDeriveBLSFromSeed)]
+pub fn key_derive_bls_from_seed(seed: JsValue, path: String) -> Result<ExtendedKey, JsValue> {
+    set_panic_hook();
+
+    let seed_bytes = Zeroizing::new(extract_bytes(
+        seed,
+        "Seed must be a valid hexstring, base64 or a buffer",
+    )?);
+
+    let key_address = filecoin_signer::key_derive_bls_from_seed(&seed_bytes, &path)
+        .map_err(|e| JsValue::from(format!("Error deriving key: {}", e)))?;
+
+    Ok(ExtendedKey { 0: key_address })
+}
+
+#[wasm_bindgen(js_name = keyGenerateBLS)]
+pub fn key_generate_bls(testnet: bool) -> Result<ExtendedKey, JsValue> {
+    set_panic_hook();
+
+    let key_address = filecoin_signer::key_generate_bls(testnet)
+        .map_err(|e| JsValue::from(format!("Error generating key: {}", e)))?;
+
+    Ok(ExtendedKey { 0: key_address })
+}
+
+#[wasm_bindgen(js_name = keyImportLotus)]
+pub fn key_import_lotus(lotus_key: String, testnet: bool) -> Result<ExtendedKey, JsValue> {
+    set_panic_hook();
+
+    let lotus_key = Zeroizing::new(lotus_key);
+
+    let key_address = filecoin_signer::key_import_lotus(&lotus_key, testnet)
+        .map_err(|e| JsValue::from(format!("Error importing key: {}", e)))?;
+
+    Ok(ExtendedKey { 0: key_address })
+}
+
+#[wasm_bindgen(js_name = keyExportLotus)]
+pub fn key_export_lotus(extended_key: &ExtendedKey) -> Result<String, JsValue> {
+    set_panic_hook();
+
+    let lotus_key = filecoin_signer::key_export_lotus(&extended_key.0)
+        .map_err(|e| JsValue::from(format!("Error exporting key: {}", e)))?;
+
+    Ok(lotus_key)
+}
+
 #[wasm_bindgen(js_name = transactionSerialize)]
 pub fn transaction_serialize(message: JsValue) -> Result<String, JsValue> {
     set_panic_hook();
@@ -235,6 +433,69 @@ pub fn transaction_sign(
     Ok(signed_message_js)
 }
 
+#[wasm_bindgen(js_name = transactionSignStrict)]
+pub fn transaction_sign_strict(
+    unsigned_tx_js: JsValue,
+    private_key_js: JsValue,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+
+    let unsigned_message = unsigned_tx_js
+        .into_serde()
+        .map_err(|e| JsValue::from(format!("Error parsing parameters: {}", e)))?;
+
+    let private_key_bytes = extract_private_key(private_key_js)?;
+
+    let signed_message =
+        filecoin_signer::transaction_sign_strict(&unsigned_message, &private_key_bytes)
+            .map_err(|e| JsValue::from_str(format!("Error signing transaction: {}", e).as_str()))?;
+
+    let signed_message_js = JsValue::from_serde(&signed_message)
+        .map_err(|e| JsValue::from(format!("Error signing transaction: {}", e)))?;
+
+    Ok(signed_message_js)
+}
+
+#[wasm_bindgen(js_name = messageValidate)]
+pub fn message_validate(
+    unsigned_tx_js: JsValue,
+    actor_type: Option<String>,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+
+    let unsigned_message = unsigned_tx_js
+        .into_serde()
+        .map_err(|e| JsValue::from(format!("Error parsing parameters: {}", e)))?;
+
+    let validation = filecoin_signer::message_validate(&unsigned_message, actor_type.as_deref());
+
+    JsValue::from_serde(&validation)
+        .map_err(|e| JsValue::from(format!("Error validating transaction: {}", e)))
+}
+
+#[wasm_bindgen(js_name = transactionSignBatch)]
+pub fn transaction_sign_batch(
+    unsigned_txs_js: JsValue,
+    private_key_js: JsValue,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+
+    let unsigned_messages: Vec<UnsignedMessageAPI> = unsigned_txs_js
+        .into_serde()
+        .map_err(|e| JsValue::from(format!("Error parsing parameters: {}", e)))?;
+
+    let private_key = extract_private_key(private_key_js)?;
+
+    let results: Vec<BatchResultAPI<SignedMessageAPI>> =
+        filecoin_signer::transaction_sign_batch(&unsigned_messages, &private_key)
+            .into_iter()
+            .map(BatchResultAPI::from)
+            .collect();
+
+    JsValue::from_serde(&results)
+        .map_err(|e| JsValue::from(format!("Error signing transactions: {}", e)))
+}
+
 #[wasm_bindgen(js_name = transactionSignLotus)]
 pub fn transaction_sign_lotus(
     unsigned_tx_js: JsValue,
@@ -299,6 +560,147 @@ pub fn verify_signature(signature_js: JsValue, message_js: JsValue) -> Result<bo
         .map_err(|e| JsValue::from_str(format!("Error verifying signature: {}", e).as_str()))
 }
 
+#[wasm_bindgen(js_name = verifySignatureBatch)]
+pub fn verify_signature_batch(signatures_js: JsValue) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+
+    if !js_sys::Array::is_array(&signatures_js) {
+        return Err(JsValue::from("Signatures must be an array"));
+    }
+
+    let signatures = js_sys::Array::from(&signatures_js)
+        .iter()
+        .map(|item| {
+            let signature_js = js_sys::Reflect::get(&item, &JsValue::from("signature"))?;
+            let signature_bytes = extract_bytes(
+                signature_js,
+                "Signature must be encoded as hexstring, base64 or a buffer",
+            )?;
+            let signature =
+                Signature::try_from(signature_bytes).map_err(|e| JsValue::from(e.to_string()))?;
+
+            let message_js = js_sys::Reflect::get(&item, &JsValue::from("message"))?;
+            let message_bytes = extract_bytes(
+                message_js,
+                "Message must be encoded as hexstring, base64 or a buffer",
+            )?;
+
+            Ok((signature, CborBuffer(message_bytes)))
+        })
+        .collect::<Result<Vec<_>, JsValue>>()?;
+
+    let results: Vec<BatchResultAPI<bool>> = filecoin_signer::verify_signature_batch(&signatures)
+        .into_iter()
+        .map(BatchResultAPI::from)
+        .collect();
+
+    JsValue::from_serde(&results)
+        .map_err(|e| JsValue::from(format!("Error verifying signatures: {}", e)))
+}
+
+#[wasm_bindgen(js_name = verifySignedMessage)]
+pub fn verify_signed_message(signed_message_js: JsValue) -> Result<bool, JsValue> {
+    set_panic_hook();
+
+    let signed_message = signed_message_js
+        .into_serde()
+        .map_err(|e| JsValue::from(format!("Error parsing parameters: {}", e)))?;
+
+    filecoin_signer::verify_signed_message(&signed_message)
+        .map_err(|e| JsValue::from(format!("Error verifying signature: {}", e)))
+}
+
+#[wasm_bindgen(js_name = signBytes)]
+pub fn sign_bytes(
+    data_js: JsValue,
+    address: String,
+    private_key_js: JsValue,
+    prefixed: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+
+    let data = extract_bytes(
+        data_js,
+        "Data must be encoded as hexstring, base64 or a buffer",
+    )?;
+
+    let private_key = extract_private_key(private_key_js)?;
+
+    let signature = filecoin_signer::sign_bytes(&data, &address, &private_key, prefixed)
+        .map_err(|e| JsValue::from(format!("Error signing data: {}", e)))?;
+
+    JsValue::from_serde(&SignatureAPI::from(&signature))
+        .map_err(|e| JsValue::from(format!("Error signing data: {}", e)))
+}
+
+#[wasm_bindgen(js_name = verifyBytes)]
+pub fn verify_bytes(
+    signature_js: JsValue,
+    address: String,
+    data_js: JsValue,
+    prefixed: bool,
+) -> Result<bool, JsValue> {
+    set_panic_hook();
+
+    let signature_bytes = extract_bytes(
+        signature_js,
+        "Signature must be encoded as hexstring, base64 or a buffer",
+    )?;
+
+    let signature =
+        Signature::try_from(signature_bytes).map_err(|e| JsValue::from(e.to_string()))?;
+
+    let data = extract_bytes(
+        data_js,
+        "Data must be encoded as hexstring, base64 or a buffer",
+    )?;
+
+    filecoin_signer::verify_bytes(&signature, &address, &data, prefixed)
+        .map_err(|e| JsValue::from(format!("Error verifying signature: {}", e)))
+}
+
+#[wasm_bindgen(js_name = aggregateBLSSignatures)]
+pub fn aggregate_bls_signatures(signatures_js: JsValue) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+
+    if !js_sys::Array::is_array(&signatures_js) {
+        return Err(JsValue::from("Signatures must be an array"));
+    }
+
+    let signatures = js_sys::Array::from(&signatures_js)
+        .iter()
+        .map(|signature_js| {
+            let signature_bytes = extract_bytes(
+                signature_js,
+                "Signature must be encoded as hexstring, base64 or a buffer",
+            )?;
+
+            SignatureBLS::try_from(signature_bytes).map_err(|e| JsValue::from(e.to_string()))
+        })
+        .collect::<Result<Vec<_>, JsValue>>()?;
+
+    let signature = filecoin_signer::aggregate_bls_signatures(&signatures)
+        .map_err(|e| JsValue::from(format!("Error aggregating signatures: {}", e)))?;
+
+    JsValue::from_serde(&SignatureAPI::from(&Signature::SignatureBLS(signature)))
+        .map_err(|e| JsValue::from(format!("Error aggregating signatures: {}", e)))
+}
+
+#[wasm_bindgen(js_name = aggregateBLSMessages)]
+pub fn aggregate_bls_messages(signed_messages_js: JsValue) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+
+    let signed_messages: Vec<SignedMessageAPI> = signed_messages_js
+        .into_serde()
+        .map_err(|e| JsValue::from(format!("Error parsing parameters: {}", e)))?;
+
+    let aggregate = filecoin_signer::aggregate_bls_messages(&signed_messages)
+        .map_err(|e| JsValue::from(format!("Error aggregating signatures: {}", e)))?;
+
+    JsValue::from_serde(&aggregate)
+        .map_err(|e| JsValue::from(format!("Error aggregating signatures: {}", e)))
+}
+
 fn signer_value_to_string(address_value: JsValue) -> Result<String, JsValue> {
     let address = address_value.as_string();
 
@@ -991,3 +1393,15 @@ pub fn get_cid(message: JsValue) -> Result<String, JsValue> {
 
     Ok(result)
 }
+
+#[wasm_bindgen(js_name = predictRobustAddress)]
+pub fn predict_robust_address(unsigned_message: JsValue) -> Result<String, JsValue> {
+    set_panic_hook();
+
+    let unsigned_message: UnsignedMessageAPI = unsigned_message
+        .into_serde()
+        .map_err(|e| JsValue::from(format!("Error parsing parameters: {}", e)))?;
+
+    filecoin_signer::predict_robust_address(&unsigned_message)
+        .map_err(|e| JsValue::from(format!("Error predicting the robust address: {}", e)))
+}
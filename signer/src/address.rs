@@ -0,0 +1,545 @@
+use std::str::FromStr;
+
+use blake2b_simd::Params;
+use data_encoding::BASE32_NOPAD;
+use serde::{Deserialize, Serialize};
+
+use crate::delegated_address::{self, DelegatedAddress};
+use crate::error::SignerError;
+use crate::utils::{leb128_decode, leb128_encode};
+
+const CHECKSUM_SIZE: usize = 4;
+const HASH_PAYLOAD_SIZE: usize = 20;
+const BLS_PAYLOAD_SIZE: usize = 48;
+/// Longest ID payload: a u64 encoded as LEB128
+const MAX_ID_PAYLOAD_SIZE: usize = 10;
+
+/// Address protocol
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressProtocol {
+    /// `0`: actor ID
+    Id,
+    /// `1`: blake2b-160 hash of a secp256k1 public key
+    Secp256k1,
+    /// `2`: robust actor address
+    Actor,
+    /// `3`: BLS public key
+    Bls,
+    /// `4`: sub-address managed by another actor (e.g f410 Ethereum addresses)
+    Delegated,
+}
+
+impl AddressProtocol {
+    pub fn from_byte(protocol: u8) -> Result<Self, SignerError> {
+        match protocol {
+            0 => Ok(AddressProtocol::Id),
+            1 => Ok(AddressProtocol::Secp256k1),
+            2 => Ok(AddressProtocol::Actor),
+            3 => Ok(AddressProtocol::Bls),
+            4 => Ok(AddressProtocol::Delegated),
+            _ => Err(SignerError::GenericString(format!(
+                "Unknown address protocol `{}`",
+                protocol
+            ))),
+        }
+    }
+
+    pub fn to_byte(self) -> u8 {
+        match self {
+            AddressProtocol::Id => 0,
+            AddressProtocol::Secp256k1 => 1,
+            AddressProtocol::Actor => 2,
+            AddressProtocol::Bls => 3,
+            AddressProtocol::Delegated => delegated_address::DELEGATED_PROTOCOL,
+        }
+    }
+}
+
+/// Address network, given by the `f` or `t` prefix
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressNetwork {
+    Mainnet,
+    Testnet,
+}
+
+/// Target form of `address_convert`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressFormat {
+    /// `f` prefixed string
+    Mainnet,
+    /// `t` prefixed string
+    Testnet,
+    /// Hexadecimal protocol byte followed by the payload (the on-chain encoding)
+    Bytes,
+    /// `0x` Ethereum address, only for f410 addresses
+    Eth,
+}
+
+impl FromStr for AddressFormat {
+    type Err = SignerError;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "mainnet" => Ok(AddressFormat::Mainnet),
+            "testnet" => Ok(AddressFormat::Testnet),
+            "bytes" => Ok(AddressFormat::Bytes),
+            "eth" => Ok(AddressFormat::Eth),
+            _ => Err(SignerError::GenericString(format!(
+                "Unknown address format `{}` (mainnet, testnet, bytes or eth)",
+                format
+            ))),
+        }
+    }
+}
+
+/// Description of an address
+///
+/// A wrong checksum does not make the inspection fail (`checksum_valid` is `false`), the other
+/// network forms are only given for a valid checksum.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AddressInfo {
+    pub protocol: AddressProtocol,
+    pub network: AddressNetwork,
+    pub payload_hexstring: String,
+    /// `None` for ID addresses, which have no checksum
+    pub checksum_hexstring: Option<String>,
+    pub checksum_valid: bool,
+    /// Protocol byte followed by the payload
+    pub bytes_hexstring: String,
+    pub mainnet_address: Option<String>,
+    pub testnet_address: Option<String>,
+    /// Actor ID of an ID address
+    pub id: Option<u64>,
+    /// Namespace of a delegated address
+    pub namespace: Option<u64>,
+    /// Ethereum address of a f410 address
+    pub eth_address: Option<String>,
+}
+
+/// Address split into its parts, the checksum being the one found in the string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedAddress {
+    pub protocol: AddressProtocol,
+    pub testnet: bool,
+    pub payload: Vec<u8>,
+    pub checksum: Option<Vec<u8>>,
+}
+
+impl ParsedAddress {
+    /// Parse an address without checking its checksum
+    pub fn parse(address: &str) -> Result<Self, SignerError> {
+        let invalid =
+            |reason: &str| SignerError::GenericString(format!("Invalid address: {}", reason));
+
+        let testnet = match address.get(0..1) {
+            Some("f") => false,
+            Some("t") => true,
+            _ => return Err(invalid("unknown network")),
+        };
+
+        let protocol = match address.get(1..2).map(u8::from_str) {
+            Some(Ok(protocol)) => AddressProtocol::from_byte(protocol)?,
+            _ => return Err(invalid("unknown protocol")),
+        };
+
+        let raw = &address[2..];
+
+        if protocol == AddressProtocol::Id {
+            if raw.is_empty()
+                || !raw.bytes().all(|c| c.is_ascii_digit())
+                || (raw.len() > 1 && raw.starts_with('0'))
+            {
+                return Err(invalid("invalid actor ID"));
+            }
+            let id = u64::from_str(raw).map_err(|_| invalid("invalid actor ID"))?;
+
+            return Ok(ParsedAddress {
+                protocol,
+                testnet,
+                payload: leb128_encode(id),
+                checksum: None,
+            });
+        }
+
+        // The namespace of a delegated address prefixes the base32 part
+        let (namespace, encoded) = if protocol == AddressProtocol::Delegated {
+            let (namespace, encoded) = raw
+                .split_once('f')
+                .ok_or_else(|| invalid("missing namespace separator"))?;
+
+            if namespace.is_empty()
+                || !namespace.bytes().all(|c| c.is_ascii_digit())
+                || (namespace.len() > 1 && namespace.starts_with('0'))
+            {
+                return Err(invalid("invalid namespace"));
+            }
+
+            (
+                Some(u64::from_str(namespace).map_err(|_| invalid("invalid namespace"))?),
+                encoded,
+            )
+        } else {
+            (None, raw)
+        };
+
+        if encoded.bytes().any(|c| c.is_ascii_uppercase()) {
+            return Err(invalid("must be lowercase"));
+        }
+        let decoded = BASE32_NOPAD
+            .decode(encoded.to_uppercase().as_bytes())
+            .map_err(|_| invalid("invalid base32 encoding"))?;
+
+        if decoded.len() < CHECKSUM_SIZE {
+            return Err(invalid("too short"));
+        }
+        let (raw_payload, checksum) = decoded.split_at(decoded.len() - CHECKSUM_SIZE);
+
+        let payload = match namespace {
+            Some(namespace) => DelegatedAddress::new(namespace, raw_payload, testnet)?.payload(),
+            None => raw_payload.to_vec(),
+        };
+
+        let parsed = ParsedAddress {
+            protocol,
+            testnet,
+            payload,
+            checksum: Some(checksum.to_vec()),
+        };
+        parsed.check_payload()?;
+
+        Ok(parsed)
+    }
+
+    /// Address of its byte form (protocol byte followed by the payload)
+    pub fn from_bytes(bytes: &[u8], testnet: bool) -> Result<Self, SignerError> {
+        let (protocol, payload) = bytes
+            .split_first()
+            .ok_or_else(|| SignerError::GenericString("Empty address".to_string()))?;
+
+        let mut parsed = ParsedAddress {
+            protocol: AddressProtocol::from_byte(*protocol)?,
+            testnet,
+            payload: payload.to_vec(),
+            checksum: None,
+        };
+        parsed.check_payload()?;
+        parsed.checksum = parsed.expected_checksum();
+
+        Ok(parsed)
+    }
+
+    /// Protocol byte followed by the payload
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.protocol.to_byte()];
+        bytes.extend_from_slice(&self.payload);
+
+        bytes
+    }
+
+    /// Checksum of the protocol and the payload, `None` for ID addresses
+    pub fn expected_checksum(&self) -> Option<Vec<u8>> {
+        if self.protocol == AddressProtocol::Id {
+            return None;
+        }
+
+        let checksum = Params::new()
+            .hash_length(CHECKSUM_SIZE)
+            .to_state()
+            .update(&[self.protocol.to_byte()])
+            .update(&self.payload)
+            .finalize();
+
+        Some(checksum.as_bytes().to_vec())
+    }
+
+    pub fn is_checksum_valid(&self) -> bool {
+        self.checksum == self.expected_checksum()
+    }
+
+    /// Encode the address on a network, with a freshly computed checksum
+    pub fn encode(&self, testnet: bool) -> Result<String, SignerError> {
+        let network = if testnet { 't' } else { 'f' };
+
+        match self.protocol {
+            AddressProtocol::Id => Ok(format!("{}0{}", network, self.id()?)),
+            AddressProtocol::Delegated => {
+                Ok(DelegatedAddress::from_payload(&self.payload, testnet)?.to_string())
+            }
+            _ => {
+                let mut encoded = self.payload.clone();
+                encoded.extend_from_slice(&self.expected_checksum().unwrap_or_default());
+
+                Ok(format!(
+                    "{}{}{}",
+                    network,
+                    self.protocol.to_byte(),
+                    BASE32_NOPAD.encode(&encoded).to_lowercase()
+                ))
+            }
+        }
+    }
+
+    fn id(&self) -> Result<u64, SignerError> {
+        let (id, size) = leb128_decode(&self.payload)?;
+
+        if size != self.payload.len() {
+            return Err(SignerError::GenericString(
+                "Invalid actor ID payload".to_string(),
+            ));
+        }
+
+        Ok(id)
+    }
+
+    fn check_payload(&self) -> Result<(), SignerError> {
+        let valid = match self.protocol {
+            AddressProtocol::Id => self.payload.len() <= MAX_ID_PAYLOAD_SIZE && self.id().is_ok(),
+            AddressProtocol::Secp256k1 | AddressProtocol::Actor => {
+                self.payload.len() == HASH_PAYLOAD_SIZE
+            }
+            AddressProtocol::Bls => self.payload.len() == BLS_PAYLOAD_SIZE,
+            AddressProtocol::Delegated => {
+                DelegatedAddress::from_payload(&self.payload, self.testnet).is_ok()
+            }
+        };
+
+        if !valid {
+            return Err(SignerError::GenericString(format!(
+                "Invalid payload length for a {:?} address",
+                self.protocol
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Describe an address: protocol, network, payload and checksum
+///
+/// # Arguments
+///
+/// * `address` - A `f` or `t` prefixed address of any protocol
+///
+pub fn inspect(address: &str) -> Result<AddressInfo, SignerError> {
+    let parsed = ParsedAddress::parse(address)?;
+    let checksum_valid = parsed.is_checksum_valid();
+
+    let (mainnet_address, testnet_address) = if checksum_valid {
+        (Some(parsed.encode(false)?), Some(parsed.encode(true)?))
+    } else {
+        (None, None)
+    };
+
+    let (namespace, eth_address) = match parsed.protocol {
+        AddressProtocol::Delegated => {
+            let delegated = DelegatedAddress::from_payload(&parsed.payload, parsed.testnet)?;
+
+            (Some(delegated.namespace), delegated.eth_address().ok())
+        }
+        _ => (None, None),
+    };
+
+    Ok(AddressInfo {
+        protocol: parsed.protocol,
+        network: if parsed.testnet {
+            AddressNetwork::Testnet
+        } else {
+            AddressNetwork::Mainnet
+        },
+        payload_hexstring: hex::encode(&parsed.payload),
+        checksum_hexstring: parsed.checksum.as_ref().map(hex::encode),
+        checksum_valid,
+        bytes_hexstring: hex::encode(parsed.to_bytes()),
+        mainnet_address,
+        testnet_address,
+        id: match parsed.protocol {
+            AddressProtocol::Id => Some(parsed.id()?),
+            _ => None,
+        },
+        namespace,
+        eth_address,
+    })
+}
+
+/// Re-encode an address, its checksum must be valid
+///
+/// # Arguments
+///
+/// * `address` - A `f` or `t` prefixed address, or a `0x` Ethereum address (seen as a f410 address)
+/// * `format` - the target form
+///
+pub fn convert(address: &str, format: AddressFormat) -> Result<String, SignerError> {
+    let parsed = if address.starts_with("0x") || address.starts_with("0X") {
+        let delegated = DelegatedAddress::from_eth_address(address, false)?;
+
+        ParsedAddress::from_bytes(
+            &[
+                &[delegated_address::DELEGATED_PROTOCOL][..],
+                &delegated.payload(),
+            ]
+            .concat(),
+            false,
+        )?
+    } else {
+        ParsedAddress::parse(address)?
+    };
+
+    if !parsed.is_checksum_valid() {
+        return Err(SignerError::GenericString(
+            "Invalid address checksum".to_string(),
+        ));
+    }
+
+    match format {
+        AddressFormat::Mainnet => parsed.encode(false),
+        AddressFormat::Testnet => parsed.encode(true),
+        AddressFormat::Bytes => Ok(hex::encode(parsed.to_bytes())),
+        AddressFormat::Eth => match parsed.protocol {
+            AddressProtocol::Delegated => {
+                DelegatedAddress::from_payload(&parsed.payload, parsed.testnet)?.eth_address()
+            }
+            _ => Err(SignerError::GenericString(
+                "Only f410 addresses have an Ethereum form".to_string(),
+            )),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::address::{
+        convert, inspect, AddressFormat, AddressNetwork, AddressProtocol, ParsedAddress,
+    };
+
+    const SECP_ADDRESS: &str = "f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba";
+    const BLS_ADDRESS: &str =
+        "t3smdzzt2fbrzalmfi5rskc3tc6wpwcj2zbgyu5engqtkkzrxteg2oyqpukqzrhqqfvzqadh7mtqye443liejq";
+    const DELEGATED_ADDRESS: &str = "f410fkkld55ioe7qg24wvt7fu6pbknb56ht7pt4zamxa";
+
+    #[test]
+    fn inspect_secp256k1() {
+        let info = inspect(SECP_ADDRESS).unwrap();
+
+        assert_eq!(info.protocol, AddressProtocol::Secp256k1);
+        assert_eq!(info.network, AddressNetwork::Mainnet);
+        assert_eq!(
+            info.payload_hexstring,
+            "1eaf1c8a4bbfeeb0870b1745b1f57503470b7116"
+        );
+        assert_eq!(
+            info.bytes_hexstring,
+            "011eaf1c8a4bbfeeb0870b1745b1f57503470b7116"
+        );
+        assert!(info.checksum_valid);
+        assert_eq!(info.mainnet_address.as_deref(), Some(SECP_ADDRESS));
+        assert_eq!(
+            info.testnet_address.as_deref(),
+            Some("t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba")
+        );
+        assert!(info.id.is_none());
+    }
+
+    #[test]
+    fn inspect_invalid_checksum() {
+        // Last character changed
+        let info = inspect("f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6fpxba").unwrap();
+
+        assert!(!info.checksum_valid);
+        assert!(info.mainnet_address.is_none());
+        assert!(convert(
+            "f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6fpxba",
+            AddressFormat::Testnet
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn inspect_other_protocols() {
+        let info = inspect("t01024").unwrap();
+        assert_eq!(info.protocol, AddressProtocol::Id);
+        assert_eq!(info.network, AddressNetwork::Testnet);
+        assert_eq!(info.id, Some(1024));
+        assert_eq!(info.bytes_hexstring, "008008");
+        assert!(info.checksum_hexstring.is_none());
+        assert!(info.checksum_valid);
+
+        let info = inspect(BLS_ADDRESS).unwrap();
+        assert_eq!(info.protocol, AddressProtocol::Bls);
+        assert_eq!(info.payload_hexstring.len(), 96);
+
+        let info = inspect(DELEGATED_ADDRESS).unwrap();
+        assert_eq!(info.protocol, AddressProtocol::Delegated);
+        assert_eq!(info.namespace, Some(10));
+        assert_eq!(
+            info.payload_hexstring,
+            "0a52963ef50e27e06d72d59fcb4f3c2a687be3cfef"
+        );
+        assert_eq!(
+            info.eth_address.as_deref(),
+            Some("0x52963EF50e27e06D72D59fcB4F3c2a687BE3cfEf")
+        );
+
+        assert!(inspect("f5abc").is_err());
+        assert!(inspect("x1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba").is_err());
+        assert!(inspect("f0012").is_err());
+        // Truncated secp256k1 payload
+        assert!(inspect("f1d2xrzcslx7xlbbylc5c3d5lvandqw4i").is_err());
+    }
+
+    #[test]
+    fn convert_network() {
+        assert_eq!(
+            convert(SECP_ADDRESS, AddressFormat::Testnet).unwrap(),
+            "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba"
+        );
+        assert_eq!(
+            convert(BLS_ADDRESS, AddressFormat::Mainnet).unwrap(),
+            format!("f{}", &BLS_ADDRESS[1..])
+        );
+        assert_eq!(convert("f099", AddressFormat::Testnet).unwrap(), "t099");
+        assert_eq!(
+            convert(DELEGATED_ADDRESS, AddressFormat::Testnet).unwrap(),
+            format!("t{}", &DELEGATED_ADDRESS[1..])
+        );
+    }
+
+    #[test]
+    fn convert_payload_forms() {
+        let bytes = convert(SECP_ADDRESS, AddressFormat::Bytes).unwrap();
+        let parsed = ParsedAddress::from_bytes(&hex::decode(&bytes).unwrap(), false).unwrap();
+        assert_eq!(parsed.encode(false).unwrap(), SECP_ADDRESS);
+
+        let bytes = convert(DELEGATED_ADDRESS, AddressFormat::Bytes).unwrap();
+        assert_eq!(bytes, "040a52963ef50e27e06d72d59fcb4f3c2a687be3cfef");
+        let parsed = ParsedAddress::from_bytes(&hex::decode(&bytes).unwrap(), false).unwrap();
+        assert_eq!(parsed.encode(false).unwrap(), DELEGATED_ADDRESS);
+
+        assert_eq!(
+            convert(DELEGATED_ADDRESS, AddressFormat::Eth).unwrap(),
+            "0x52963EF50e27e06D72D59fcB4F3c2a687BE3cfEf"
+        );
+        assert_eq!(
+            convert(
+                "0x52963EF50e27e06D72D59fcB4F3c2a687BE3cfEf",
+                AddressFormat::Mainnet
+            )
+            .unwrap(),
+            DELEGATED_ADDRESS
+        );
+        assert!(convert(SECP_ADDRESS, AddressFormat::Eth).is_err());
+
+        assert_eq!(
+            AddressFormat::from_str("testnet").unwrap(),
+            AddressFormat::Testnet
+        );
+        assert!(AddressFormat::from_str("hex").is_err());
+
+        assert!(ParsedAddress::from_bytes(&[1, 2, 3], false).is_err());
+        assert!(ParsedAddress::from_bytes(&[], false).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use std::convert::{TryFrom, TryInto};
+use std::fmt;
 use std::str::FromStr;
 
 use forest_address::{Address, Network};
@@ -8,11 +9,13 @@ use forest_message::{Message, SignedMessage, UnsignedMessage};
 use forest_vm::Serialized;
 use num_bigint_chainsafe::BigInt;
 use serde::{Deserialize, Serialize, Serializer};
+use zeroize::Zeroize;
 
 use extras::{multisig, paych, ExecParams};
 
 use crate::error::SignerError;
 use crate::signature::Signature;
+use crate::{ExtendedKey, PublicKey, REDACTED};
 
 pub enum SigTypes {
     SigTypeSecp256k1 = 0x01,
@@ -692,6 +695,114 @@ pub struct SignedMessageAPI {
     pub signature: SignatureAPI,
 }
 
+/// Aggregated signature of BLS messages with the CIDs of the messages, in the same order
+#[derive(Debug, Clone, Deserialize, PartialEq, Serialize)]
+pub struct BLSAggregateAPI {
+    pub signature: SignatureAPI,
+    pub cids: Vec<String>,
+}
+
+/// Result of one item of a batch, `{ "ok": ... }` or `{ "error": "..." }`
+#[derive(Debug, Clone, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchResultAPI<T> {
+    Ok(T),
+    Error(String),
+}
+
+impl<T> From<Result<T, SignerError>> for BatchResultAPI<T> {
+    fn from(result: Result<T, SignerError>) -> BatchResultAPI<T> {
+        match result {
+            Ok(value) => BatchResultAPI::Ok(value),
+            Err(err) => BatchResultAPI::Error(err.to_string()),
+        }
+    }
+}
+
+/// Kind of a problem found by `message_validate`
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageIssueKind {
+    InvalidAddress,
+    NetworkMismatch,
+    InvalidAmount,
+    NegativeAmount,
+    ValueAboveSupply,
+    InvalidGasLimit,
+    ZeroGasFeeCap,
+    GasPremiumAboveFeeCap,
+    InvalidParams,
+    UnexpectedParams,
+}
+
+/// Problem found in a field of a message
+#[derive(Debug, Clone, Deserialize, PartialEq, Serialize)]
+pub struct MessageIssueAPI {
+    pub field: String,
+    pub kind: MessageIssueKind,
+    pub message: String,
+}
+
+/// Result of `message_validate`, a message with errors is refused by the strict sign functions
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Serialize)]
+pub struct MessageValidationAPI {
+    pub errors: Vec<MessageIssueAPI>,
+    pub warnings: Vec<MessageIssueAPI>,
+}
+
+impl MessageValidationAPI {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Lotus key type
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Serialize)]
+pub enum LotusKeyType {
+    #[serde(rename = "secp256k1")]
+    Secp256k1,
+    #[serde(rename = "bls")]
+    BLS,
+}
+
+/// Lotus KeyInfo structure (`lotus wallet export` output once hex decoded)
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct LotusKeyInfo {
+    #[serde(rename = "Type")]
+    pub key_type: LotusKeyType,
+    #[serde(rename = "PrivateKey", with = "serde_base64_vector")]
+    pub private_key: Vec<u8>,
+}
+
+impl From<&ExtendedKey> for LotusKeyInfo {
+    fn from(extended_key: &ExtendedKey) -> LotusKeyInfo {
+        let key_type = match extended_key.public_key {
+            PublicKey::PublicKeySECP256K1(_) => LotusKeyType::Secp256k1,
+            PublicKey::BLSPublicKey(_) => LotusKeyType::BLS,
+        };
+
+        LotusKeyInfo {
+            key_type,
+            private_key: extended_key.private_key.0.to_vec(),
+        }
+    }
+}
+
+impl fmt::Debug for LotusKeyInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LotusKeyInfo")
+            .field("key_type", &self.key_type)
+            .field("private_key", &format_args!("{}", REDACTED))
+            .finish()
+    }
+}
+
+impl Drop for LotusKeyInfo {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
 /// Structure containing an `UnsignedMessageAPI` or a `SignedMessageAPI`
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(untagged)]
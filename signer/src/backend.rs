@@ -0,0 +1,220 @@
+use std::convert::TryFrom;
+
+use bls_signatures::Serialize;
+use forest_address::{Address, Protocol};
+use secp256k1::{sign, Message};
+
+use crate::error::SignerError;
+use crate::signature::{SignatureBLS, SignatureSECP256K1};
+use crate::PrivateKey;
+
+/// Key able to sign Filecoin payloads without exposing its secret (e.g a HSM, a KMS or a separate process)
+///
+/// The protocol of `address` selects the signing scheme: secp256k1 keys sign 32 bytes blake2b
+/// digests, BLS keys sign bytes. A signer only implements the method matching its scheme.
+pub trait Signer {
+    /// Address of the key, only its protocol and payload are used
+    fn address(&self) -> Result<Address, SignerError>;
+
+    /// Sign a 32 bytes digest with secp256k1 and return a RSV signature
+    fn sign_digest(&self, _digest: &[u8; 32]) -> Result<SignatureSECP256K1, SignerError> {
+        Err(SignerError::GenericString(
+            "Signer does not support secp256k1 signatures".to_string(),
+        ))
+    }
+
+    /// Sign bytes with BLS
+    fn sign_bytes(&self, _bytes: &[u8]) -> Result<SignatureBLS, SignerError> {
+        Err(SignerError::GenericString(
+            "Signer does not support BLS signatures".to_string(),
+        ))
+    }
+}
+
+/// In-memory `Signer` backed by a `PrivateKey`
+pub enum PrivateKeySigner<'a> {
+    Secp256k1(&'a PrivateKey),
+    Bls(&'a PrivateKey),
+}
+
+impl<'a> Signer for PrivateKeySigner<'a> {
+    fn address(&self) -> Result<Address, SignerError> {
+        match self {
+            PrivateKeySigner::Secp256k1(private_key) => {
+                let secret_key = secp256k1::SecretKey::parse_slice(&private_key.0)?;
+                let public_key = secp256k1::PublicKey::from_secret_key(&secret_key);
+
+                Ok(Address::new_secp256k1(&public_key.serialize())?)
+            }
+            PrivateKeySigner::Bls(private_key) => {
+                let sk = bls_signatures::PrivateKey::from_bytes(&private_key.0)?;
+
+                Ok(Address::new_bls(&sk.public_key().as_bytes())?)
+            }
+        }
+    }
+
+    fn sign_digest(&self, digest: &[u8; 32]) -> Result<SignatureSECP256K1, SignerError> {
+        match self {
+            PrivateKeySigner::Secp256k1(private_key) => {
+                let secret_key = secp256k1::SecretKey::parse_slice(&private_key.0)?;
+
+                let (signature_rs, recovery_id) = sign(&Message::parse(digest), &secret_key);
+
+                let mut signature = SignatureSECP256K1 { 0: [0; 65] };
+                signature.0[..64].copy_from_slice(&signature_rs.serialize()[..]);
+                signature.0[64] = recovery_id.serialize();
+
+                Ok(signature)
+            }
+            PrivateKeySigner::Bls(_) => Err(SignerError::GenericString(
+                "BLS key cannot sign a secp256k1 digest".to_string(),
+            )),
+        }
+    }
+
+    fn sign_bytes(&self, bytes: &[u8]) -> Result<SignatureBLS, SignerError> {
+        match self {
+            PrivateKeySigner::Bls(private_key) => {
+                let sk = bls_signatures::PrivateKey::from_bytes(&private_key.0)?;
+
+                Ok(SignatureBLS::try_from(sk.sign(bytes).as_bytes())?)
+            }
+            PrivateKeySigner::Secp256k1(_) => Err(SignerError::GenericString(
+                "Secp256k1 key cannot sign BLS bytes".to_string(),
+            )),
+        }
+    }
+}
+
+/// Check that a signer holds the key of `address`, whatever the network of `address`
+///
+/// # Arguments
+///
+/// * `signer` - the signer
+/// * `address` - the address expected to sign (e.g the `from` of a message)
+///
+pub fn ensure_signer_address<S: Signer + ?Sized>(
+    signer: &S,
+    address: &Address,
+) -> Result<(), SignerError> {
+    let signer_address = signer.address()?;
+
+    if signer_address.protocol() != address.protocol()
+        || signer_address.payload_bytes() != address.payload_bytes()
+    {
+        return Err(SignerError::GenericString(format!(
+            "Signer address {} does not match {}",
+            signer_address, address
+        )));
+    }
+
+    match address.protocol() {
+        Protocol::Secp256k1 | Protocol::BLS => Ok(()),
+        _ => Err(SignerError::GenericString(
+            "Signer address should be BLS or Secp256k1".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use bls_signatures::Serialize;
+    use forest_address::Address;
+
+    use crate::backend::{ensure_signer_address, PrivateKeySigner, Signer};
+    use crate::error::SignerError;
+    use crate::signature::{SignatureBLS, SignatureSECP256K1};
+    use crate::{key_recover, key_recover_bls, PrivateKey};
+
+    const PRIVATE_KEY: &str = "8VcW07ADswS4BV2cxi5rnIadVsyTDDhY1NfDH19T8Uo=";
+    const BLS_PRIVATE_KEY: &str = "P2pSgkvsZSgi0LOczuHmSXT1+l/hvSs3fVBb4y8OgVo=";
+
+    /// Signer forwarding to a key it never exposes, counting the signing requests
+    struct RemoteSigner {
+        key: PrivateKey,
+        requests: Cell<usize>,
+    }
+
+    impl Signer for RemoteSigner {
+        fn address(&self) -> Result<Address, SignerError> {
+            PrivateKeySigner::Secp256k1(&self.key).address()
+        }
+
+        fn sign_digest(&self, digest: &[u8; 32]) -> Result<SignatureSECP256K1, SignerError> {
+            self.requests.set(self.requests.get() + 1);
+            PrivateKeySigner::Secp256k1(&self.key).sign_digest(digest)
+        }
+    }
+
+    #[test]
+    fn private_key_signer_address() {
+        let private_key = PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap();
+        let extended_key = key_recover(&private_key, false).unwrap();
+        let address = PrivateKeySigner::Secp256k1(&private_key).address().unwrap();
+        assert_eq!(address.to_string(), extended_key.address);
+
+        let bls_private_key = PrivateKey::try_from(BLS_PRIVATE_KEY.to_string()).unwrap();
+        let extended_key = key_recover_bls(&bls_private_key, false).unwrap();
+        let address = PrivateKeySigner::Bls(&bls_private_key).address().unwrap();
+        assert_eq!(address.to_string(), extended_key.address);
+    }
+
+    #[test]
+    fn private_key_signer_schemes() {
+        let private_key = PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap();
+        let signer = PrivateKeySigner::Secp256k1(&private_key);
+        assert!(signer.sign_digest(&[7u8; 32]).is_ok());
+        assert!(signer.sign_bytes(b"data").is_err());
+
+        let bls_private_key = PrivateKey::try_from(BLS_PRIVATE_KEY.to_string()).unwrap();
+        let signer = PrivateKeySigner::Bls(&bls_private_key);
+        assert!(signer.sign_digest(&[7u8; 32]).is_err());
+
+        let signature: SignatureBLS = signer.sign_bytes(b"data").unwrap();
+        let public_key =
+            bls_signatures::PublicKey::from_bytes(&signer.address().unwrap().payload_bytes())
+                .unwrap();
+        let signature = bls_signatures::Signature::from_bytes(&signature.0).unwrap();
+        assert!(public_key.verify(signature, b"data"));
+    }
+
+    #[test]
+    fn custom_signer_default_methods() {
+        let signer = RemoteSigner {
+            key: PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap(),
+            requests: Cell::new(0),
+        };
+
+        let private_key = PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap();
+        let expected = PrivateKeySigner::Secp256k1(&private_key)
+            .sign_digest(&[7u8; 32])
+            .unwrap();
+
+        assert_eq!(
+            signer.sign_digest(&[7u8; 32]).unwrap().0[..],
+            expected.0[..]
+        );
+        assert_eq!(signer.requests.get(), 1);
+
+        // Not implemented by the signer
+        assert!(signer.sign_bytes(b"data").is_err());
+    }
+
+    #[test]
+    fn signer_address_check() {
+        let private_key = PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap();
+        let signer = PrivateKeySigner::Secp256k1(&private_key);
+        let address = key_recover(&private_key, true).unwrap().address;
+
+        // Network does not matter
+        assert!(ensure_signer_address(&signer, &Address::from_str(&address).unwrap()).is_ok());
+
+        let other = Address::from_str("t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy").unwrap();
+        assert!(ensure_signer_address(&signer, &other).is_err());
+    }
+}
@@ -0,0 +1,235 @@
+use hkdf::Hkdf;
+use num_bigint_chainsafe::BigUint;
+use num_traits::Zero;
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+use zeroize::Zeroize;
+
+use crate::derivation_path::DerivationPath;
+use crate::error::SignerError;
+
+/// Order of the BLS12-381 scalar field
+const BLS_CURVE_ORDER: &[u8; 64] =
+    b"73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+const KEYGEN_SALT: &[u8; 20] = b"BLS-SIG-KEYGEN-SALT-";
+const HKDF_MOD_R_OKM_SIZE: usize = 48;
+const LAMPORT_CHUNK_SIZE: usize = 32;
+const LAMPORT_CHUNKS: usize = 255;
+const MIN_SEED_SIZE: usize = 32;
+
+pub const BLS_SECRET_KEY_SIZE: usize = 32;
+
+/// EIP-2334 purpose used for BLS paths
+pub const BLS_PATH_PURPOSE: u32 = 12381;
+
+/// BLS secret key derived following EIP-2333 (big-endian scalar)
+#[derive(Zeroize)]
+#[zeroize(drop)]
+pub struct BLSSecretKey([u8; BLS_SECRET_KEY_SIZE]);
+
+impl BLSSecretKey {
+    /// Derive the master secret key from a seed (`derive_master_SK`)
+    pub fn from_seed(seed: &[u8]) -> Result<Self, SignerError> {
+        if seed.len() < MIN_SEED_SIZE {
+            return Err(SignerError::GenericString(
+                "Seed should be at least 32 bytes".to_string(),
+            ));
+        }
+
+        hkdf_mod_r(seed)
+    }
+
+    /// Generate a secret key from some input key material (IETF `KeyGen`)
+    pub fn from_ikm(ikm: &[u8]) -> Result<Self, SignerError> {
+        hkdf_mod_r(ikm)
+    }
+
+    /// Derive a child secret key (`derive_child_SK`)
+    pub fn derive_child_key(&self, index: u32) -> Result<BLSSecretKey, SignerError> {
+        let compressed_lamport_pk = self.lamport_public_key(index)?;
+
+        hkdf_mod_r(&compressed_lamport_pk)
+    }
+
+    /// Derive the secret key at the end of `path`, starting from this key
+    pub fn derive_path(&self, path: &[u32]) -> Result<BLSSecretKey, SignerError> {
+        let mut esk = BLSSecretKey(self.0);
+
+        for index in path {
+            esk = esk.derive_child_key(*index)?;
+        }
+
+        Ok(esk)
+    }
+
+    /// Secret key serialized as Filecoin expects it (little-endian)
+    #[inline]
+    pub fn secret_key(&self) -> [u8; BLS_SECRET_KEY_SIZE] {
+        let mut sk = self.0;
+        sk.reverse();
+        sk
+    }
+
+    fn lamport_public_key(&self, index: u32) -> Result<[u8; 32], SignerError> {
+        let salt = index.to_be_bytes();
+
+        let mut not_ikm = self.0;
+        not_ikm.iter_mut().for_each(|b| *b = !*b);
+
+        let lamport_0 = ikm_to_lamport_secret_key(&self.0, &salt)?;
+        let lamport_1 = ikm_to_lamport_secret_key(&not_ikm, &salt)?;
+        not_ikm.zeroize();
+
+        let mut hasher = Sha256::new();
+        for chunk in lamport_0
+            .chunks(LAMPORT_CHUNK_SIZE)
+            .chain(lamport_1.chunks(LAMPORT_CHUNK_SIZE))
+        {
+            hasher.update(Sha256::digest(chunk));
+        }
+
+        let mut compressed_lamport_pk = [0u8; 32];
+        compressed_lamport_pk.copy_from_slice(&hasher.finalize());
+
+        Ok(compressed_lamport_pk)
+    }
+}
+
+fn ikm_to_lamport_secret_key(ikm: &[u8], salt: &[u8]) -> Result<Vec<u8>, SignerError> {
+    let hkdf = Hkdf::<Sha256>::new(Some(salt), ikm);
+
+    let mut okm = vec![0u8; LAMPORT_CHUNK_SIZE * LAMPORT_CHUNKS];
+    hkdf.expand(&[], &mut okm)
+        .map_err(|_| SignerError::GenericString("Invalid HKDF output length".to_string()))?;
+
+    Ok(okm)
+}
+
+fn hkdf_mod_r(ikm: &[u8]) -> Result<BLSSecretKey, SignerError> {
+    let curve_order = BigUint::parse_bytes(BLS_CURVE_ORDER, 16)
+        .ok_or_else(|| SignerError::GenericString("Invalid curve order".to_string()))?;
+
+    let mut ikm_padded = ikm.to_vec();
+    ikm_padded.push(0);
+
+    let mut salt = KEYGEN_SALT.to_vec();
+
+    loop {
+        salt = Sha256::digest(&salt).to_vec();
+
+        let hkdf = Hkdf::<Sha256>::new(Some(&salt), &ikm_padded);
+
+        let mut okm = [0u8; HKDF_MOD_R_OKM_SIZE];
+        hkdf.expand(&(HKDF_MOD_R_OKM_SIZE as u16).to_be_bytes(), &mut okm)
+            .map_err(|_| SignerError::GenericString("Invalid HKDF output length".to_string()))?;
+
+        let sk = BigUint::from_bytes_be(&okm) % &curve_order;
+        okm.zeroize();
+
+        if !sk.is_zero() {
+            let sk_bytes = sk.to_bytes_be();
+
+            let mut secret_key = BLSSecretKey([0; BLS_SECRET_KEY_SIZE]);
+            secret_key.0[BLS_SECRET_KEY_SIZE - sk_bytes.len()..].copy_from_slice(&sk_bytes);
+            ikm_padded.zeroize();
+
+            return Ok(secret_key);
+        }
+    }
+}
+
+/// Parse an EIP-2334 path (e.g `m/12381/461/0/0`)
+pub fn parse_bls_path(path: &str) -> Result<Vec<u32>, SignerError> {
+    let derivation_path = DerivationPath::from_str(path)?;
+
+    if !derivation_path.is_absolute() {
+        return Err(SignerError::GenericString(
+            "BLS path should start with `m`".to_string(),
+        ));
+    }
+
+    if derivation_path.has_hardened() {
+        return Err(SignerError::GenericString(
+            "BLS path components should be non hardened integers".to_string(),
+        ));
+    }
+
+    if derivation_path.indexes().first() != Some(&BLS_PATH_PURPOSE) {
+        return Err(SignerError::GenericString(format!(
+            "BLS path purpose should be {}",
+            BLS_PATH_PURPOSE
+        )));
+    }
+
+    Ok(derivation_path.indexes().to_vec())
+}
+
+/// Return `true` if the coin type of an EIP-2334 path is the testnet one
+pub fn is_testnet_bls_path(path: &[u32]) -> bool {
+    path.get(1) == Some(&1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bls_key::{is_testnet_bls_path, parse_bls_path, BLSSecretKey};
+    use hex::decode;
+    use num_bigint_chainsafe::BigUint;
+
+    fn to_decimal(sk: &BLSSecretKey) -> String {
+        BigUint::from_bytes_le(&sk.secret_key()).to_str_radix(10)
+    }
+
+    #[test]
+    fn eip2333_test_case_0() {
+        let seed = decode("c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04").unwrap();
+
+        let master = BLSSecretKey::from_seed(&seed).unwrap();
+        assert_eq!(
+            to_decimal(&master),
+            "6083874454709270928345386274498605044986640685124978867557563392430687146096"
+        );
+
+        let child = master.derive_child_key(0).unwrap();
+        assert_eq!(
+            to_decimal(&child),
+            "20397789859736650942317412262472558107875392172444076792671091975210932703118"
+        );
+    }
+
+    #[test]
+    fn eip2333_test_case_1() {
+        let seed =
+            decode("3141592653589793238462643383279502884197169399375105820974944592").unwrap();
+
+        let master = BLSSecretKey::from_seed(&seed).unwrap();
+        assert_eq!(
+            to_decimal(&master),
+            "29757020647961307431480504535336562678282505419141012933316116377660817309383"
+        );
+
+        let child = master.derive_child_key(3141592653).unwrap();
+        assert_eq!(
+            to_decimal(&child),
+            "25457201688850691947727629385191704516744796114925897962676248250929345014287"
+        );
+    }
+
+    #[test]
+    fn seed_too_short() {
+        assert!(BLSSecretKey::from_seed(&[0u8; 16]).is_err());
+    }
+
+    #[test]
+    fn parse_path() {
+        let path = parse_bls_path("m/12381/461/0/0").unwrap();
+        assert_eq!(path, vec![12381, 461, 0, 0]);
+        assert!(!is_testnet_bls_path(&path));
+
+        let path = parse_bls_path("m/12381/1/0/0").unwrap();
+        assert!(is_testnet_bls_path(&path));
+
+        assert!(parse_bls_path("m/44'/461'/0/0/0").is_err());
+        assert!(parse_bls_path("m/12381/461'/0/0").is_err());
+        assert!(parse_bls_path("12381/461/0/0").is_err());
+    }
+}
@@ -0,0 +1,337 @@
+use std::fmt;
+use std::str::FromStr;
+
+use blake2b_simd::Params;
+use data_encoding::BASE32_NOPAD;
+use sha3::{Digest, Keccak256};
+
+use crate::error::SignerError;
+use crate::utils::{leb128_decode, leb128_encode};
+
+/// Protocol of the delegated addresses
+pub const DELEGATED_PROTOCOL: u8 = 4;
+/// Actor ID of the Ethereum Address Manager, namespace of the f410 addresses
+pub const EAM_NAMESPACE: u64 = 10;
+/// Size of an Ethereum address
+pub const ETH_ADDRESS_SIZE: usize = 20;
+/// Maximum size of the sub-address of a delegated address
+pub const MAX_SUBADDRESS_SIZE: usize = 54;
+
+const CHECKSUM_SIZE: usize = 4;
+
+/// Delegated (f4) address: a sub-address managed by the actor `namespace`
+///
+/// The string form is `<network>4<namespace>f<base32(sub-address + checksum)>`, the checksum
+/// being the 4 bytes blake2b hash of the protocol, the leb128 encoded namespace and the
+/// sub-address. Ethereum addresses are managed by the EAM (namespace 10) and give f410 addresses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelegatedAddress {
+    pub namespace: u64,
+    pub subaddress: Vec<u8>,
+    pub testnet: bool,
+}
+
+impl DelegatedAddress {
+    pub fn new(namespace: u64, subaddress: &[u8], testnet: bool) -> Result<Self, SignerError> {
+        if subaddress.len() > MAX_SUBADDRESS_SIZE {
+            return Err(SignerError::GenericString(format!(
+                "Delegated sub-address cannot be longer than {} bytes",
+                MAX_SUBADDRESS_SIZE
+            )));
+        }
+
+        Ok(DelegatedAddress {
+            namespace,
+            subaddress: subaddress.to_vec(),
+            testnet,
+        })
+    }
+
+    /// f410 address of an Ethereum address (`0x` prefixed hexadecimal)
+    pub fn from_eth_address(eth_address: &str, testnet: bool) -> Result<Self, SignerError> {
+        DelegatedAddress::new(EAM_NAMESPACE, &parse_eth_address(eth_address)?, testnet)
+    }
+
+    /// f410 address of a secp256k1 public key (compressed or not)
+    pub fn from_public_key(public_key: &[u8], testnet: bool) -> Result<Self, SignerError> {
+        DelegatedAddress::new(
+            EAM_NAMESPACE,
+            &eth_address_bytes_from_public_key(public_key)?,
+            testnet,
+        )
+    }
+
+    /// Ethereum address of a f410 address, checksummed as defined by EIP-55
+    pub fn eth_address(&self) -> Result<String, SignerError> {
+        if self.namespace != EAM_NAMESPACE || self.subaddress.len() != ETH_ADDRESS_SIZE {
+            return Err(SignerError::GenericString(
+                "Not an Ethereum (f410) address".to_string(),
+            ));
+        }
+
+        Ok(eth_address_to_checksum(&self.subaddress))
+    }
+
+    /// Delegated address of a payload (leb128 encoded namespace followed by the sub-address)
+    pub fn from_payload(payload: &[u8], testnet: bool) -> Result<Self, SignerError> {
+        let (namespace, size) = leb128_decode(payload)?;
+
+        DelegatedAddress::new(namespace, &payload[size..], testnet)
+    }
+
+    /// Address payload: leb128 encoded namespace followed by the sub-address
+    pub fn payload(&self) -> Vec<u8> {
+        let mut payload = leb128_encode(self.namespace);
+        payload.extend_from_slice(&self.subaddress);
+
+        payload
+    }
+
+    fn checksum(&self) -> Vec<u8> {
+        Params::new()
+            .hash_length(CHECKSUM_SIZE)
+            .to_state()
+            .update(&[DELEGATED_PROTOCOL])
+            .update(&self.payload())
+            .finalize()
+            .as_bytes()
+            .to_vec()
+    }
+}
+
+impl fmt::Display for DelegatedAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut encoded = self.subaddress.clone();
+        encoded.extend_from_slice(&self.checksum());
+
+        write!(
+            f,
+            "{}{}{}f{}",
+            if self.testnet { 't' } else { 'f' },
+            DELEGATED_PROTOCOL,
+            self.namespace,
+            BASE32_NOPAD.encode(&encoded).to_lowercase()
+        )
+    }
+}
+
+impl FromStr for DelegatedAddress {
+    type Err = SignerError;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| {
+            SignerError::GenericString(format!("Invalid delegated address: {}", reason))
+        };
+
+        let testnet = match address.get(0..1) {
+            Some("f") => false,
+            Some("t") => true,
+            _ => return Err(invalid("unknown network")),
+        };
+
+        if address.get(1..2) != Some("4") {
+            return Err(invalid("not a delegated address"));
+        }
+
+        let (namespace, encoded) = address[2..]
+            .split_once('f')
+            .ok_or_else(|| invalid("missing namespace separator"))?;
+
+        if namespace.is_empty()
+            || !namespace.bytes().all(|c| c.is_ascii_digit())
+            || (namespace.len() > 1 && namespace.starts_with('0'))
+        {
+            return Err(invalid("invalid namespace"));
+        }
+        let namespace = u64::from_str(namespace).map_err(|_| invalid("invalid namespace"))?;
+
+        if encoded.bytes().any(|c| c.is_ascii_uppercase()) {
+            return Err(invalid("must be lowercase"));
+        }
+        let decoded = BASE32_NOPAD
+            .decode(encoded.to_uppercase().as_bytes())
+            .map_err(|_| invalid("invalid base32 encoding"))?;
+
+        if decoded.len() < CHECKSUM_SIZE {
+            return Err(invalid("too short"));
+        }
+        let (subaddress, checksum) = decoded.split_at(decoded.len() - CHECKSUM_SIZE);
+
+        let delegated_address = DelegatedAddress::new(namespace, subaddress, testnet)?;
+
+        if delegated_address.checksum() != checksum {
+            return Err(invalid("invalid checksum"));
+        }
+
+        Ok(delegated_address)
+    }
+}
+
+/// Ethereum address of a secp256k1 public key (compressed or not), checksummed as defined by EIP-55
+pub fn eth_address_from_public_key(public_key: &[u8]) -> Result<String, SignerError> {
+    Ok(eth_address_to_checksum(&eth_address_bytes_from_public_key(
+        public_key,
+    )?))
+}
+
+/// Parse a `0x` prefixed Ethereum address
+///
+/// Mixed case addresses must carry a valid EIP-55 checksum, all lowercase or all uppercase
+/// addresses are accepted as is.
+pub fn parse_eth_address(eth_address: &str) -> Result<[u8; ETH_ADDRESS_SIZE], SignerError> {
+    let hex_address = eth_address
+        .strip_prefix("0x")
+        .or_else(|| eth_address.strip_prefix("0X"))
+        .ok_or_else(|| {
+            SignerError::GenericString("Ethereum address must start with `0x`".to_string())
+        })?;
+
+    if hex_address.len() != ETH_ADDRESS_SIZE * 2 {
+        return Err(SignerError::GenericString(format!(
+            "Ethereum address must be {} bytes long",
+            ETH_ADDRESS_SIZE
+        )));
+    }
+
+    let mut address = [0u8; ETH_ADDRESS_SIZE];
+    address.copy_from_slice(&hex::decode(hex_address)?);
+
+    let has_lowercase = hex_address.bytes().any(|c| c.is_ascii_lowercase());
+    let has_uppercase = hex_address.bytes().any(|c| c.is_ascii_uppercase());
+
+    if has_lowercase && has_uppercase && eth_address_to_checksum(&address)[2..] != *hex_address {
+        return Err(SignerError::GenericString(
+            "Invalid Ethereum address checksum".to_string(),
+        ));
+    }
+
+    Ok(address)
+}
+
+fn eth_address_bytes_from_public_key(
+    public_key: &[u8],
+) -> Result<[u8; ETH_ADDRESS_SIZE], SignerError> {
+    let public_key = secp256k1::PublicKey::parse_slice(public_key, None)?;
+
+    // Keccak-256 of the uncompressed key without its 0x04 prefix, the address is the last 20 bytes
+    let hash = Keccak256::digest(&public_key.serialize()[1..]);
+
+    let mut address = [0u8; ETH_ADDRESS_SIZE];
+    address.copy_from_slice(&hash[hash.len() - ETH_ADDRESS_SIZE..]);
+
+    Ok(address)
+}
+
+// EIP-55: a letter is uppercase when the matching nibble of the Keccak-256 hash of the lowercase
+// hexadecimal address is 8 or more
+fn eth_address_to_checksum(address: &[u8]) -> String {
+    let hex_address = hex::encode(address);
+    let hash = Keccak256::digest(hex_address.as_bytes());
+
+    let checksummed: String = hex_address
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    format!("0x{}", checksummed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::delegated_address::{
+        eth_address_from_public_key, eth_address_to_checksum, parse_eth_address, DelegatedAddress,
+    };
+
+    const ETH_ADDRESS: &str = "0x52963EF50e27e06D72D59fcB4F3c2a687BE3cfEf";
+    const DELEGATED_ADDRESS: &str = "f410fkkld55ioe7qg24wvt7fu6pbknb56ht7pt4zamxa";
+
+    #[test]
+    fn eth_address_checksum() {
+        // EIP-55 test vector
+        let address = parse_eth_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap();
+        assert_eq!(
+            eth_address_to_checksum(&address),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+
+        assert!(parse_eth_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_ok());
+        assert!(parse_eth_address("0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED").is_ok());
+        assert!(parse_eth_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD").is_err());
+        assert!(parse_eth_address("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").is_err());
+        assert!(parse_eth_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea").is_err());
+    }
+
+    #[test]
+    fn eth_to_delegated() {
+        let address = DelegatedAddress::from_eth_address(ETH_ADDRESS, false).unwrap();
+        assert_eq!(address.to_string(), DELEGATED_ADDRESS);
+
+        let address = DelegatedAddress::from_eth_address(ETH_ADDRESS, true).unwrap();
+        assert_eq!(address.to_string(), format!("t{}", &DELEGATED_ADDRESS[1..]));
+    }
+
+    #[test]
+    fn delegated_to_eth() {
+        let address = DelegatedAddress::from_str(DELEGATED_ADDRESS).unwrap();
+
+        assert_eq!(address.namespace, 10);
+        assert!(!address.testnet);
+        assert_eq!(address.eth_address().unwrap(), ETH_ADDRESS);
+
+        // Other namespaces are valid delegated addresses but not Ethereum addresses
+        let address = DelegatedAddress::new(32, &[1, 2, 3], true).unwrap();
+        let parsed = DelegatedAddress::from_str(&address.to_string()).unwrap();
+        assert_eq!(parsed, address);
+        assert!(parsed.eth_address().is_err());
+
+        assert_eq!(
+            DelegatedAddress::from_payload(&address.payload(), true).unwrap(),
+            address
+        );
+    }
+
+    #[test]
+    fn invalid_delegated_address() {
+        // Wrong checksum
+        assert!(
+            DelegatedAddress::from_str("f410fkkld55ioe7qg24wvt7fu6pbknb56ht7pt4zamxb").is_err()
+        );
+        // Uppercase
+        assert!(
+            DelegatedAddress::from_str("f410FKKLD55IOE7QG24WVT7FU6PBKNB56HT7PT4ZAMXA").is_err()
+        );
+        // Not delegated
+        assert!(DelegatedAddress::from_str("f1rovwtiuo5ncslpmpjftzu5akswbgsgighjazxoi").is_err());
+        assert!(DelegatedAddress::from_str("f4kkld55ioe7qg24wvt7fu6pbknb56ht7pt4zamxa").is_err());
+        assert!(
+            DelegatedAddress::from_str("f4010fkkld55ioe7qg24wvt7fu6pbknb56ht7pt4zamxa").is_err()
+        );
+        assert!(DelegatedAddress::new(10, &[0u8; 55], false).is_err());
+    }
+
+    #[test]
+    fn public_key_to_eth() {
+        let secret_key = secp256k1::SecretKey::parse(&[1u8; 32]).unwrap();
+        let public_key = secp256k1::PublicKey::from_secret_key(&secret_key);
+
+        let uncompressed = eth_address_from_public_key(&public_key.serialize()).unwrap();
+        let compressed = eth_address_from_public_key(&public_key.serialize_compressed()).unwrap();
+        assert_eq!(uncompressed, compressed);
+
+        // Private key 0x01..01
+        assert_eq!(uncompressed, "0x1a642f0E3c3aF545E7AcBD38b07251B3990914F1");
+
+        assert!(eth_address_from_public_key(&[0u8; 10]).is_err());
+    }
+}
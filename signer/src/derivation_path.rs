@@ -0,0 +1,221 @@
+use std::fmt;
+use std::str::FromStr;
+
+use zx_bip44::BIP44Path;
+
+use crate::error::SignerError;
+use crate::extended_key::HARDENED_BIT;
+
+const TESTNET_COIN_TYPE: u32 = 1;
+
+/// Derivation path of any depth
+///
+/// A path starting with `m` is absolute (derived from a master key), otherwise it is relative
+/// to an existing extended key. Hardened indexes are marked with `'` or `h` (e.g `m/44'/461h/0/0/0`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivationPath {
+    absolute: bool,
+    indexes: Vec<u32>,
+}
+
+impl DerivationPath {
+    /// Absolute path (`m/...`) from child indexes
+    pub fn absolute(indexes: &[u32]) -> Self {
+        DerivationPath {
+            absolute: true,
+            indexes: indexes.to_vec(),
+        }
+    }
+
+    /// Relative path from child indexes
+    pub fn relative(indexes: &[u32]) -> Self {
+        DerivationPath {
+            absolute: false,
+            indexes: indexes.to_vec(),
+        }
+    }
+
+    #[inline]
+    pub fn is_absolute(&self) -> bool {
+        self.absolute
+    }
+
+    #[inline]
+    pub fn indexes(&self) -> &[u32] {
+        &self.indexes
+    }
+
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.indexes.len()
+    }
+
+    /// Return `true` if any index of the path is hardened
+    pub fn has_hardened(&self) -> bool {
+        self.indexes.iter().any(|index| index & HARDENED_BIT != 0)
+    }
+
+    /// Return `true` if the coin type (second level of an absolute path) is the testnet one
+    pub fn is_testnet(&self) -> bool {
+        self.absolute
+            && self.indexes.get(1).map_or(false, |coin_type| {
+                coin_type & !HARDENED_BIT == TESTNET_COIN_TYPE
+            })
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = SignerError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let path = path.trim();
+
+        let (absolute, components) = match path {
+            "" => (false, ""),
+            "m" | "M" => (true, ""),
+            _ => match path.strip_prefix("m/").or_else(|| path.strip_prefix("M/")) {
+                Some(components) => (true, components),
+                None => (false, path),
+            },
+        };
+
+        let indexes = if components.is_empty() {
+            Vec::new()
+        } else {
+            components
+                .split('/')
+                .map(parse_index)
+                .collect::<Result<Vec<u32>, SignerError>>()?
+        };
+
+        Ok(DerivationPath { absolute, indexes })
+    }
+}
+
+impl From<&BIP44Path> for DerivationPath {
+    fn from(path: &BIP44Path) -> Self {
+        DerivationPath::absolute(&path.0)
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let components = self
+            .indexes
+            .iter()
+            .map(|index| {
+                if index & HARDENED_BIT == 0 {
+                    index.to_string()
+                } else {
+                    format!("{}'", index & !HARDENED_BIT)
+                }
+            })
+            .collect::<Vec<String>>();
+
+        if self.absolute {
+            write!(f, "m")?;
+            for component in components {
+                write!(f, "/{}", component)?;
+            }
+            Ok(())
+        } else {
+            write!(f, "{}", components.join("/"))
+        }
+    }
+}
+
+fn parse_index(component: &str) -> Result<u32, SignerError> {
+    let (index, hardened) = match component
+        .strip_suffix('\'')
+        .or_else(|| component.strip_suffix('h'))
+        .or_else(|| component.strip_suffix('H'))
+    {
+        Some(index) => (index, true),
+        None => (component, false),
+    };
+
+    if index.is_empty() || !index.chars().all(|c| c.is_ascii_digit()) {
+        return Err(SignerError::InvalidDerivationPath(format!(
+            "invalid component `{}`",
+            component
+        )));
+    }
+
+    let index = index
+        .parse::<u32>()
+        .ok()
+        .filter(|index| index & HARDENED_BIT == 0)
+        .ok_or_else(|| {
+            SignerError::InvalidDerivationPath(format!("component `{}` is out of range", component))
+        })?;
+
+    Ok(if hardened {
+        index | HARDENED_BIT
+    } else {
+        index
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::derivation_path::DerivationPath;
+    use crate::extended_key::HARDENED_BIT;
+    use std::str::FromStr;
+
+    #[test]
+    fn parse_bip44_path() {
+        let path = DerivationPath::from_str("m/44'/461'/0/0/1").unwrap();
+
+        assert!(path.is_absolute());
+        assert!(!path.is_testnet());
+        assert_eq!(
+            path.indexes(),
+            &[44 | HARDENED_BIT, 461 | HARDENED_BIT, 0, 0, 1]
+        );
+        assert_eq!(path.to_string(), "m/44'/461'/0/0/1");
+    }
+
+    #[test]
+    fn parse_any_depth() {
+        let path = DerivationPath::from_str("m/44h/1h/0h").unwrap();
+        assert_eq!(
+            path.indexes(),
+            &[44 | HARDENED_BIT, 1 | HARDENED_BIT, HARDENED_BIT]
+        );
+        assert!(path.is_testnet());
+        assert_eq!(path.to_string(), "m/44'/1'/0'");
+
+        let path = DerivationPath::from_str("m/12381/461/0/0").unwrap();
+        assert_eq!(path.indexes(), &[12381, 461, 0, 0]);
+        assert!(!path.has_hardened());
+
+        let path = DerivationPath::from_str("m").unwrap();
+        assert!(path.is_absolute());
+        assert_eq!(path.depth(), 0);
+    }
+
+    #[test]
+    fn parse_relative_path() {
+        let path = DerivationPath::from_str("0/5'").unwrap();
+
+        assert!(!path.is_absolute());
+        assert!(!path.is_testnet());
+        assert_eq!(path.indexes(), &[0, 5 | HARDENED_BIT]);
+        assert_eq!(path.to_string(), "0/5'");
+
+        let path = DerivationPath::from_str("").unwrap();
+        assert!(!path.is_absolute());
+        assert_eq!(path.depth(), 0);
+    }
+
+    #[test]
+    fn parse_invalid_path() {
+        assert!(DerivationPath::from_str("m/44'/461'/a").is_err());
+        assert!(DerivationPath::from_str("m//0").is_err());
+        assert!(DerivationPath::from_str("m/0/").is_err());
+        assert!(DerivationPath::from_str("m/-1").is_err());
+        assert!(DerivationPath::from_str("m/+1").is_err());
+        assert!(DerivationPath::from_str("m/2147483648").is_err());
+        assert!(DerivationPath::from_str("0/1/m").is_err());
+    }
+}
@@ -0,0 +1,330 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+
+use num_bigint_chainsafe::BigInt;
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+
+use crate::error::SignerError;
+use crate::extended_key::HARDENED_BIT;
+use crate::key_derive_from_seed;
+
+/// Number of consecutive unused addresses after which the discovery of a layout stops
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+
+const FILECOIN_COIN_TYPE: u32 = 461;
+const TESTNET_COIN_TYPE: u32 = 1;
+
+/// Future returned by a `ChainBackend`
+pub type ChainBackendFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T, SignerError>> + Send + 'a>>;
+
+/// On-chain state of an address
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountState {
+    pub nonce: u64,
+    /// Balance in attoFIL
+    pub balance: String,
+}
+
+impl AccountState {
+    /// An address is used once it has sent a message or holds funds
+    pub fn is_used(&self) -> Result<bool, SignerError> {
+        let balance = BigInt::from_str(&self.balance)
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+        Ok(self.nonce > 0 || !balance.is_zero())
+    }
+}
+
+/// Source of the on-chain state of addresses (e.g a Lotus node)
+pub trait ChainBackend {
+    /// Return the state of an address, an address unknown to the chain has a zero nonce and balance
+    fn account_state<'a>(&'a self, address: &'a str) -> ChainBackendFuture<'a, AccountState>;
+}
+
+/// Derivation path layout scanned during discovery
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscoveryLayout {
+    /// `m/44'/461'/0'/0/i`
+    AddressIndex,
+    /// `m/44'/461'/i'/0/0`
+    AccountIndex,
+    /// `m/44'/461'/0/0/i`, the account is not hardened
+    UnhardenedAddressIndex,
+}
+
+impl DiscoveryLayout {
+    /// Derivation path of the address at `index`
+    pub fn path(&self, index: u32, testnet: bool) -> String {
+        let coin_type = if testnet {
+            TESTNET_COIN_TYPE
+        } else {
+            FILECOIN_COIN_TYPE
+        };
+
+        match self {
+            DiscoveryLayout::AddressIndex => format!("m/44'/{}'/0'/0/{}", coin_type, index),
+            DiscoveryLayout::AccountIndex => format!("m/44'/{}'/{}'/0/0", coin_type, index),
+            DiscoveryLayout::UnhardenedAddressIndex => {
+                format!("m/44'/{}'/0/0/{}", coin_type, index)
+            }
+        }
+    }
+}
+
+/// Account discovery parameters
+#[derive(Debug, Clone)]
+pub struct DiscoveryOptions {
+    pub layouts: Vec<DiscoveryLayout>,
+    /// Number of consecutive unused addresses after which the discovery of a layout stops
+    pub gap_limit: u32,
+    pub testnet: bool,
+}
+
+impl Default for DiscoveryOptions {
+    fn default() -> Self {
+        DiscoveryOptions {
+            layouts: vec![
+                DiscoveryLayout::AddressIndex,
+                DiscoveryLayout::AccountIndex,
+                DiscoveryLayout::UnhardenedAddressIndex,
+            ],
+            gap_limit: DEFAULT_GAP_LIMIT,
+            testnet: false,
+        }
+    }
+}
+
+/// Used address found during discovery
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiscoveredAccount {
+    pub path: String,
+    pub address: String,
+    pub nonce: u64,
+    pub balance: String,
+}
+
+/// Walk the derivation path layouts of a seed and return the used addresses
+///
+/// Each layout is scanned from index 0 until `gap_limit` consecutive addresses are unused.
+/// An address shared by several layouts (index 0) is only returned once.
+///
+/// # Arguments
+///
+/// * `backend` - source of the on-chain state of the addresses
+/// * `seed` - A seed as bytes array
+/// * `options` - layouts, gap limit and network to scan
+///
+pub async fn discover_accounts<B: ChainBackend + Sync>(
+    backend: &B,
+    seed: &[u8],
+    options: &DiscoveryOptions,
+) -> Result<Vec<DiscoveredAccount>, SignerError> {
+    if options.gap_limit == 0 {
+        return Err(SignerError::GenericString(
+            "Gap limit must be at least 1".to_string(),
+        ));
+    }
+
+    let mut accounts: Vec<DiscoveredAccount> = Vec::new();
+
+    for layout in options.layouts.iter() {
+        let mut gap = 0;
+        let mut index = 0;
+
+        while gap < options.gap_limit && index < HARDENED_BIT {
+            let path = layout.path(index, options.testnet);
+            let address = key_derive_from_seed(seed, &path)?.address;
+
+            let state = backend.account_state(&address).await?;
+
+            if state.is_used()? {
+                gap = 0;
+
+                if !accounts.iter().any(|account| account.address == address) {
+                    accounts.push(DiscoveredAccount {
+                        path,
+                        address,
+                        nonce: state.nonce,
+                        balance: state.balance,
+                    });
+                }
+            } else {
+                gap += 1;
+            }
+
+            index += 1;
+        }
+    }
+
+    Ok(accounts)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use futures::executor::block_on;
+
+    use crate::discovery::{
+        discover_accounts, AccountState, ChainBackend, ChainBackendFuture, DiscoveryLayout,
+        DiscoveryOptions,
+    };
+    use crate::key_derive_from_seed;
+
+    const SEED: &str = "000102030405060708090a0b0c0d0e0f";
+
+    /// In-memory chain keeping track of the queried addresses
+    #[derive(Default)]
+    struct FakeChain {
+        accounts: HashMap<String, AccountState>,
+        queried: Mutex<Vec<String>>,
+    }
+
+    impl FakeChain {
+        fn with_used_paths(paths: &[&str]) -> Self {
+            let seed = hex::decode(SEED).unwrap();
+            let mut chain = FakeChain::default();
+
+            for (i, path) in paths.iter().enumerate() {
+                let address = key_derive_from_seed(&seed, path).unwrap().address;
+                chain.accounts.insert(
+                    address,
+                    AccountState {
+                        nonce: i as u64,
+                        balance: "1000".to_string(),
+                    },
+                );
+            }
+
+            chain
+        }
+
+        fn queried_count(&self) -> usize {
+            self.queried.lock().unwrap().len()
+        }
+    }
+
+    impl ChainBackend for FakeChain {
+        fn account_state<'a>(&'a self, address: &'a str) -> ChainBackendFuture<'a, AccountState> {
+            self.queried.lock().unwrap().push(address.to_string());
+
+            let state = self.accounts.get(address).cloned().unwrap_or(AccountState {
+                nonce: 0,
+                balance: "0".to_string(),
+            });
+
+            Box::pin(async move { Ok(state) })
+        }
+    }
+
+    #[test]
+    fn layout_paths() {
+        assert_eq!(
+            DiscoveryLayout::AddressIndex.path(3, false),
+            "m/44'/461'/0'/0/3"
+        );
+        assert_eq!(
+            DiscoveryLayout::AccountIndex.path(3, false),
+            "m/44'/461'/3'/0/0"
+        );
+        assert_eq!(
+            DiscoveryLayout::AccountIndex.path(3, true),
+            "m/44'/1'/3'/0/0"
+        );
+        assert_eq!(
+            DiscoveryLayout::UnhardenedAddressIndex.path(3, false),
+            "m/44'/461'/0/0/3"
+        );
+    }
+
+    #[test]
+    fn account_state_usage() {
+        let unused = AccountState {
+            nonce: 0,
+            balance: "0".to_string(),
+        };
+        assert!(!unused.is_used().unwrap());
+
+        let funded = AccountState {
+            nonce: 0,
+            balance: "1".to_string(),
+        };
+        assert!(funded.is_used().unwrap());
+
+        let invalid = AccountState {
+            nonce: 0,
+            balance: "abc".to_string(),
+        };
+        assert!(invalid.is_used().is_err());
+    }
+
+    #[test]
+    fn discover_within_gap_limit() {
+        let chain = FakeChain::with_used_paths(&[
+            "m/44'/461'/0'/0/0",
+            "m/44'/461'/0'/0/2",
+            "m/44'/461'/0'/0/5",
+            // Beyond the gap limit
+            "m/44'/461'/0'/0/9",
+            "m/44'/461'/1'/0/0",
+            "m/44'/461'/0/0/1",
+        ]);
+        let seed = hex::decode(SEED).unwrap();
+
+        let options = DiscoveryOptions {
+            gap_limit: 3,
+            ..DiscoveryOptions::default()
+        };
+
+        let accounts = block_on(discover_accounts(&chain, &seed, &options)).unwrap();
+        let paths: Vec<&str> = accounts
+            .iter()
+            .map(|account| account.path.as_str())
+            .collect();
+
+        assert_eq!(
+            paths,
+            vec![
+                "m/44'/461'/0'/0/0",
+                "m/44'/461'/0'/0/2",
+                "m/44'/461'/0'/0/5",
+                "m/44'/461'/1'/0/0",
+                "m/44'/461'/0/0/1",
+            ]
+        );
+        assert_eq!(accounts[1].nonce, 1);
+        assert_eq!(accounts[1].balance, "1000");
+
+        // Address layout: 0 to 5 then 3 unused, account layout: 0 to 1 then 3 unused,
+        // unhardened address layout: 0 to 1 then 3 unused
+        assert_eq!(chain.queried_count(), 9 + 5 + 5);
+    }
+
+    #[test]
+    fn discover_nothing() {
+        let chain = FakeChain::default();
+        let seed = hex::decode(SEED).unwrap();
+
+        let options = DiscoveryOptions {
+            layouts: vec![DiscoveryLayout::AddressIndex],
+            testnet: true,
+            ..DiscoveryOptions::default()
+        };
+
+        let accounts = block_on(discover_accounts(&chain, &seed, &options)).unwrap();
+
+        assert!(accounts.is_empty());
+        assert_eq!(chain.queried_count(), options.gap_limit as usize);
+
+        let options = DiscoveryOptions {
+            gap_limit: 0,
+            ..DiscoveryOptions::default()
+        };
+        assert!(block_on(discover_accounts(&chain, &seed, &options)).is_err());
+    }
+}
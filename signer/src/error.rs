@@ -32,6 +32,12 @@ pub enum SignerError {
     /// Invalid BIP44Path
     #[error("Invalid BIP44 path : `{0}`")]
     InvalidBIP44Path(#[from] zx_bip44::errors::BIP44PathError),
+    /// Invalid derivation path
+    #[error("Invalid derivation path : `{0}`")]
+    InvalidDerivationPath(String),
+    /// IO error
+    #[error("IO error | {0}")]
+    IO(#[from] std::io::Error),
     /// BLS error
     #[error("Couldn't convert from slice")]
     TryFromSlice(#[from] TryFromSliceError),
@@ -59,6 +65,8 @@ impl From<SignerError> for ffi_support::ExternError {
             SignerError::ParseIntError(_) => 7,
             SignerError::BLS(_) => 8,
             SignerError::InvalidBIP44Path(_) => 8,
+            SignerError::InvalidDerivationPath(_) => 14,
+            SignerError::IO(_) => 15,
             SignerError::TryFromSlice(_) => 10,
             SignerError::DecodeError(_) => 11,
             SignerError::DeserializeError(_) => 12,
@@ -1,16 +1,26 @@
 use secp256k1::util::{COMPRESSED_PUBLIC_KEY_SIZE, FULL_PUBLIC_KEY_SIZE, SECRET_KEY_SIZE};
 use secp256k1::{PublicKey, SecretKey};
 
+use crate::derivation_path::DerivationPath;
 use crate::error::SignerError;
+use crate::REDACTED;
 use hmac::{Hmac, Mac, NewMac};
-use sha2::Sha512;
+use ripemd160::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
 use std::convert::TryFrom;
 use std::fmt;
 use zeroize::Zeroize;
 use zx_bip44::BIP44Path;
 
 const HMAC_SEED: &[u8; 12] = b"Bitcoin seed";
-const HARDENED_BIT: u32 = 1 << 31;
+pub const HARDENED_BIT: u32 = 1 << 31;
+
+const XPRV_VERSION: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+const XPUB_VERSION: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+const TPRV_VERSION: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+const TPUB_VERSION: [u8; 4] = [0x04, 0x35, 0x87, 0xCF];
+const SERIALIZED_EXTENDED_KEY_SIZE: usize = 78;
+const FINGERPRINT_SIZE: usize = 4;
 
 #[derive(Zeroize, Debug)]
 #[zeroize(drop)]
@@ -19,21 +29,64 @@ struct ChainCode([u8; 32]);
 pub struct ExtendedSecretKey {
     secret_key: SecretKey,
     chain_code: ChainCode,
+    depth: u8,
+    parent_fingerprint: [u8; FINGERPRINT_SIZE],
+    child_number: u32,
+}
+
+pub struct ExtendedPublicKey {
+    public_key: PublicKey,
+    chain_code: ChainCode,
+    depth: u8,
+    parent_fingerprint: [u8; FINGERPRINT_SIZE],
+    child_number: u32,
 }
 
 type HmacSha512 = Hmac<Sha512>;
 
 impl fmt::Display for ExtendedSecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretKey/ChainCode:  {}/{}", REDACTED, REDACTED)
+    }
+}
+
+impl fmt::Debug for ExtendedSecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedSecretKey")
+            .field("secret_key", &format_args!("{}", REDACTED))
+            .field("chain_code", &format_args!("{}", REDACTED))
+            .field("depth", &self.depth)
+            .field("parent_fingerprint", &hex::encode(&self.parent_fingerprint))
+            .field("child_number", &self.child_number)
+            .finish()
+    }
+}
+
+impl fmt::Display for ExtendedPublicKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "SecretKey/ChainCode:  {:?}/{:?}",
-            hex::encode(&self.secret_key()),
+            "PublicKey/ChainCode:  {:?}/{:?}",
+            hex::encode(&self.public_key_compressed()),
             hex::encode(&self.chain_code.0)
         )
     }
 }
 
+impl From<&ExtendedSecretKey> for ExtendedPublicKey {
+    fn from(esk: &ExtendedSecretKey) -> ExtendedPublicKey {
+        ExtendedPublicKey {
+            public_key: PublicKey::from_secret_key(&esk.secret_key),
+            chain_code: ChainCode {
+                0: esk.chain_code.0,
+            },
+            depth: esk.depth,
+            parent_fingerprint: esk.parent_fingerprint,
+            child_number: esk.child_number,
+        }
+    }
+}
+
 impl TryFrom<&[u8]> for ExtendedSecretKey {
     type Error = SignerError;
 
@@ -56,14 +109,83 @@ impl ExtendedSecretKey {
         let mut tmp = ChainCode {
             0: Default::default(),
         };
+        if chain_code.len() != tmp.0.len() {
+            return Err(SignerError::GenericString(format!(
+                "Chain code must be {} bytes long",
+                tmp.0.len()
+            )));
+        }
         tmp.0.copy_from_slice(chain_code);
 
         Ok(ExtendedSecretKey {
             secret_key,
             chain_code: tmp,
+            depth: 0,
+            parent_fingerprint: [0; FINGERPRINT_SIZE],
+            child_number: 0,
         })
     }
 
+    /// Parse a BIP32 Base58Check serialized extended private key (`xprv`/`tprv`)
+    pub fn from_base58(serialized: &str) -> Result<Self, SignerError> {
+        let raw = RawExtendedKey::from_base58(serialized)?;
+
+        if raw.version != XPRV_VERSION && raw.version != TPRV_VERSION {
+            return Err(SignerError::GenericString(
+                "Not an extended private key".to_string(),
+            ));
+        }
+
+        if raw.key_data[0] != 0 {
+            return Err(SignerError::GenericString(
+                "Invalid extended private key data".to_string(),
+            ));
+        }
+
+        let mut esk =
+            ExtendedSecretKey::new(SecretKey::parse_slice(&raw.key_data[1..])?, &raw.chain_code)?;
+        esk.depth = raw.depth;
+        esk.parent_fingerprint = raw.parent_fingerprint;
+        esk.child_number = raw.child_number;
+
+        Ok(esk)
+    }
+
+    /// Serialize as a BIP32 Base58Check extended private key (`xprv`, or `tprv` for testnet)
+    pub fn to_base58(&self, testnet: bool) -> String {
+        let mut raw = RawExtendedKey {
+            version: if testnet { TPRV_VERSION } else { XPRV_VERSION },
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code.0,
+            key_data: [0; COMPRESSED_PUBLIC_KEY_SIZE],
+        };
+        raw.key_data[1..].copy_from_slice(&self.secret_key());
+
+        raw.to_base58()
+    }
+
+    #[inline]
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    #[inline]
+    pub fn parent_fingerprint(&self) -> [u8; FINGERPRINT_SIZE] {
+        self.parent_fingerprint
+    }
+
+    #[inline]
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    #[inline]
+    pub fn fingerprint(&self) -> [u8; FINGERPRINT_SIZE] {
+        fingerprint(&self.public_key_compressed())
+    }
+
     #[inline]
     pub fn secret_key(&self) -> [u8; SECRET_KEY_SIZE] {
         self.secret_key.serialize()
@@ -107,26 +229,283 @@ impl ExtendedSecretKey {
         let mut child_secret_key = self.secret_key.clone();
         child_secret_key.tweak_add_assign(&SecretKey::parse_slice(secret_key_shift)?)?;
 
-        ExtendedSecretKey::new(child_secret_key, &child_chain_code)
+        let mut child = ExtendedSecretKey::new(child_secret_key, &child_chain_code)?;
+        child.depth = child_depth(self.depth)?;
+        child.parent_fingerprint = self.fingerprint();
+        child.child_number = child_index;
+
+        Ok(child)
     }
 
     pub fn derive_bip44(&self, path: &BIP44Path) -> Result<ExtendedSecretKey, SignerError> {
-        let child0 = self.derive_child_key(path.0[0])?;
-        let child1 = child0.derive_child_key(path.0[1])?;
-        let child2 = child1.derive_child_key(path.0[2])?;
-        let child3 = child2.derive_child_key(path.0[3])?;
-        let child4 = child3.derive_child_key(path.0[4])?;
+        self.derive_path(&DerivationPath::from(path))
+    }
+
+    /// Derive the key at the end of `path`, an absolute path requires a master key
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<ExtendedSecretKey, SignerError> {
+        check_path_origin(path, self.depth)?;
+
+        let mut child = ExtendedSecretKey::new(self.secret_key.clone(), &self.chain_code.0)?;
+        child.depth = self.depth;
+        child.parent_fingerprint = self.parent_fingerprint;
+        child.child_number = self.child_number;
+
+        for child_index in path.indexes() {
+            child = child.derive_child_key(*child_index)?;
+        }
+
+        Ok(child)
+    }
+
+    #[inline]
+    pub fn extended_public_key(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey::from(self)
+    }
+}
+
+impl ExtendedPublicKey {
+    pub fn new(public_key: PublicKey, chain_code: &[u8]) -> Result<Self, SignerError> {
+        let mut tmp = ChainCode {
+            0: Default::default(),
+        };
+        if chain_code.len() != tmp.0.len() {
+            return Err(SignerError::GenericString(format!(
+                "Chain code must be {} bytes long",
+                tmp.0.len()
+            )));
+        }
+        tmp.0.copy_from_slice(chain_code);
+
+        Ok(ExtendedPublicKey {
+            public_key,
+            chain_code: tmp,
+            depth: 0,
+            parent_fingerprint: [0; FINGERPRINT_SIZE],
+            child_number: 0,
+        })
+    }
+
+    /// Parse a BIP32 Base58Check serialized extended public key (`xpub`/`tpub`)
+    pub fn from_base58(serialized: &str) -> Result<Self, SignerError> {
+        let raw = RawExtendedKey::from_base58(serialized)?;
+
+        if raw.version != XPUB_VERSION && raw.version != TPUB_VERSION {
+            return Err(SignerError::GenericString(
+                "Not an extended public key".to_string(),
+            ));
+        }
+
+        let public_key = PublicKey::parse_compressed(&raw.key_data)?;
+
+        let mut epk = ExtendedPublicKey::new(public_key, &raw.chain_code)?;
+        epk.depth = raw.depth;
+        epk.parent_fingerprint = raw.parent_fingerprint;
+        epk.child_number = raw.child_number;
+
+        Ok(epk)
+    }
+
+    /// Serialize as a BIP32 Base58Check extended public key (`xpub`, or `tpub` for testnet)
+    pub fn to_base58(&self, testnet: bool) -> String {
+        RawExtendedKey {
+            version: if testnet { TPUB_VERSION } else { XPUB_VERSION },
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code.0,
+            key_data: self.public_key_compressed(),
+        }
+        .to_base58()
+    }
+
+    #[inline]
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    #[inline]
+    pub fn parent_fingerprint(&self) -> [u8; FINGERPRINT_SIZE] {
+        self.parent_fingerprint
+    }
+
+    #[inline]
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    #[inline]
+    pub fn fingerprint(&self) -> [u8; FINGERPRINT_SIZE] {
+        fingerprint(&self.public_key_compressed())
+    }
+
+    #[inline]
+    pub fn chain_code(&self) -> [u8; SECRET_KEY_SIZE] {
+        self.chain_code.0
+    }
+
+    #[inline]
+    pub fn public_key(&self) -> [u8; FULL_PUBLIC_KEY_SIZE] {
+        self.public_key.serialize()
+    }
+
+    #[inline]
+    pub fn public_key_compressed(&self) -> [u8; COMPRESSED_PUBLIC_KEY_SIZE] {
+        self.public_key.serialize_compressed()
+    }
+
+    /// Derive a non-hardened child public key (hardened derivation requires the secret key)
+    pub fn derive_child_key(&self, child_index: u32) -> Result<ExtendedPublicKey, SignerError> {
+        if child_index & HARDENED_BIT != 0 {
+            return Err(SignerError::GenericString(
+                "Cannot derive a hardened child from a public key".to_string(),
+            ));
+        }
+
+        let mut hmac = Hmac::<Sha512>::new_varkey(&self.chain_code.0)?;
+
+        hmac.update(&self.public_key_compressed());
+        hmac.update(&child_index.to_be_bytes());
+
+        let hmac_result = hmac.finalize().into_bytes();
+        let (public_key_shift, child_chain_code) = hmac_result.split_at(32);
+
+        let mut child_public_key = self.public_key.clone();
+        child_public_key.tweak_add_assign(&SecretKey::parse_slice(public_key_shift)?)?;
+
+        let mut child = ExtendedPublicKey::new(child_public_key, &child_chain_code)?;
+        child.depth = child_depth(self.depth)?;
+        child.parent_fingerprint = self.fingerprint();
+        child.child_number = child_index;
+
+        Ok(child)
+    }
+
+    pub fn derive_children(&self, child_indexes: &[u32]) -> Result<ExtendedPublicKey, SignerError> {
+        let mut child = ExtendedPublicKey::new(self.public_key.clone(), &self.chain_code.0)?;
+        child.depth = self.depth;
+        child.parent_fingerprint = self.parent_fingerprint;
+        child.child_number = self.child_number;
+
+        for child_index in child_indexes {
+            child = child.derive_child_key(*child_index)?;
+        }
+
+        Ok(child)
+    }
+
+    /// Derive the public key at the end of `path`, an absolute path requires a master key
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<ExtendedPublicKey, SignerError> {
+        check_path_origin(path, self.depth)?;
+
+        self.derive_children(path.indexes())
+    }
+}
+
+/// Return `true` if a serialized extended key uses a testnet version (`tprv`/`tpub`)
+pub fn is_testnet_extended_key(serialized: &str) -> Result<bool, SignerError> {
+    Ok(RawExtendedKey::from_base58(serialized)?.is_testnet())
+}
+
+#[derive(Zeroize)]
+#[zeroize(drop)]
+struct RawExtendedKey {
+    version: [u8; 4],
+    depth: u8,
+    parent_fingerprint: [u8; FINGERPRINT_SIZE],
+    child_number: u32,
+    chain_code: [u8; 32],
+    key_data: [u8; COMPRESSED_PUBLIC_KEY_SIZE],
+}
+
+impl RawExtendedKey {
+    fn from_base58(serialized: &str) -> Result<Self, SignerError> {
+        let mut data = bs58::decode(serialized)
+            .with_check(None)
+            .into_vec()
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+        if data.len() != SERIALIZED_EXTENDED_KEY_SIZE {
+            data.zeroize();
+            return Err(SignerError::GenericString(
+                "Invalid extended key length".to_string(),
+            ));
+        }
+
+        let mut raw = RawExtendedKey {
+            version: [0; 4],
+            depth: data[4],
+            parent_fingerprint: [0; FINGERPRINT_SIZE],
+            child_number: 0,
+            chain_code: [0; 32],
+            key_data: [0; COMPRESSED_PUBLIC_KEY_SIZE],
+        };
+
+        let mut child_number = [0u8; 4];
+        child_number.copy_from_slice(&data[9..13]);
+
+        raw.version.copy_from_slice(&data[0..4]);
+        raw.parent_fingerprint.copy_from_slice(&data[5..9]);
+        raw.child_number = u32::from_be_bytes(child_number);
+        raw.chain_code.copy_from_slice(&data[13..45]);
+        raw.key_data
+            .copy_from_slice(&data[45..SERIALIZED_EXTENDED_KEY_SIZE]);
+        data.zeroize();
+
+        Ok(raw)
+    }
+
+    fn to_base58(&self) -> String {
+        let mut data = Vec::with_capacity(SERIALIZED_EXTENDED_KEY_SIZE);
+        data.extend_from_slice(&self.version);
+        data.push(self.depth);
+        data.extend_from_slice(&self.parent_fingerprint);
+        data.extend_from_slice(&self.child_number.to_be_bytes());
+        data.extend_from_slice(&self.chain_code);
+        data.extend_from_slice(&self.key_data);
+
+        let serialized = bs58::encode(&data).with_check().into_string();
+        data.zeroize();
+
+        serialized
+    }
 
-        Ok(child4)
+    fn is_testnet(&self) -> bool {
+        self.version == TPRV_VERSION || self.version == TPUB_VERSION
     }
 }
 
+fn fingerprint(public_key_compressed: &[u8]) -> [u8; FINGERPRINT_SIZE] {
+    let hash = Ripemd160::digest(&Sha256::digest(public_key_compressed));
+
+    let mut fingerprint = [0u8; FINGERPRINT_SIZE];
+    fingerprint.copy_from_slice(&hash[..FINGERPRINT_SIZE]);
+    fingerprint
+}
+
+fn check_path_origin(path: &DerivationPath, depth: u8) -> Result<(), SignerError> {
+    if path.is_absolute() && depth != 0 {
+        return Err(SignerError::InvalidDerivationPath(
+            "absolute path can only be derived from a master key".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn child_depth(depth: u8) -> Result<u8, SignerError> {
+    depth
+        .checked_add(1)
+        .ok_or_else(|| SignerError::GenericString("Maximum derivation depth reached".to_string()))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::extended_key::ExtendedSecretKey;
+    use crate::derivation_path::DerivationPath;
+    use crate::extended_key::{is_testnet_extended_key, ExtendedPublicKey, ExtendedSecretKey};
     use bip39::{Language, Mnemonic, Seed};
     use hex::encode;
     use std::convert::TryFrom;
+    use std::str::FromStr;
     use zx_bip44::BIP44Path;
 
     const HARDENED_BIT: u32 = 1 << 31;
@@ -208,6 +587,153 @@ mod tests {
         );
     }
 
+    #[test]
+    fn derive_any_depth_path() {
+        let phrase = "pumpkin sell climb ten list proof embark finish zero voyage congress outdoor domain city cannon leave select visual know waste tonight sauce load lift";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "");
+        let master = ExtendedSecretKey::try_from(seed.as_bytes()).unwrap();
+
+        let account_path = DerivationPath::from_str("m/44'/461'/0'").unwrap();
+        let account = master.derive_path(&account_path).unwrap();
+        assert_eq!(account.depth(), 3);
+
+        let relative_path = DerivationPath::from_str("0/7").unwrap();
+        let esk = account.derive_path(&relative_path).unwrap();
+
+        let path = DerivationPath::from_str("m/44h/461h/0h/0/7").unwrap();
+        let expected = master.derive_path(&path).unwrap();
+        assert_eq!(encode(esk.secret_key()), encode(expected.secret_key()));
+        assert_eq!(esk.depth(), 5);
+
+        let epk = account
+            .extended_public_key()
+            .derive_path(&relative_path)
+            .unwrap();
+        assert_eq!(encode(&epk.public_key()[..]), encode(&esk.public_key()[..]));
+
+        assert!(account.derive_path(&path).is_err());
+        assert!(account.extended_public_key().derive_path(&path).is_err());
+    }
+
+    #[test]
+    fn derive_public_child() {
+        let phrase = "pumpkin sell climb ten list proof embark finish zero voyage congress outdoor domain city cannon leave select visual know waste tonight sauce load lift";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "");
+        let master = ExtendedSecretKey::try_from(seed.as_bytes()).unwrap();
+
+        let account = master
+            .derive_child_key(44 | HARDENED_BIT)
+            .unwrap()
+            .derive_child_key(461 | HARDENED_BIT)
+            .unwrap()
+            .derive_child_key(0)
+            .unwrap();
+
+        let xpub = account.extended_public_key();
+        let epk = xpub.derive_children(&[0, 0]).unwrap();
+
+        let path = BIP44Path::from_string("m/44'/461'/0/0/0").unwrap();
+        let esk = master.derive_bip44(&path).unwrap();
+
+        assert_eq!(encode(&epk.public_key()[..]), encode(&esk.public_key()[..]));
+        assert_eq!(encode(epk.chain_code()), encode(esk.chain_code()));
+    }
+
+    #[test]
+    fn derive_public_hardened_child_fails() {
+        let phrase = "pumpkin sell climb ten list proof embark finish zero voyage congress outdoor domain city cannon leave select visual know waste tonight sauce load lift";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "");
+        let master = ExtendedSecretKey::try_from(seed.as_bytes()).unwrap();
+
+        let xpub = master.extended_public_key();
+
+        assert!(xpub.derive_child_key(5 | HARDENED_BIT).is_err());
+    }
+
+    #[test]
+    fn extended_key_rejects_bad_chain_code() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = ExtendedSecretKey::try_from(seed.as_slice()).unwrap();
+        let public_key = master.extended_public_key().public_key;
+
+        assert!(ExtendedPublicKey::new(public_key.clone(), &[0u8; 31]).is_err());
+        assert!(ExtendedPublicKey::new(public_key, &[0u8; 32]).is_ok());
+
+        assert!(ExtendedSecretKey::new(master.secret_key.clone(), &[0u8; 31]).is_err());
+        assert!(ExtendedSecretKey::new(master.secret_key, &[0u8; 32]).is_ok());
+    }
+
+    #[test]
+    fn serialize_bip32_test_vector_1() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = ExtendedSecretKey::try_from(seed.as_slice()).unwrap();
+
+        assert_eq!(
+            master.to_base58(false),
+            "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi"
+        );
+        assert_eq!(
+            master.extended_public_key().to_base58(false),
+            "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8"
+        );
+
+        let child = master.derive_child_key(HARDENED_BIT).unwrap();
+
+        assert_eq!(
+            child.to_base58(false),
+            "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7"
+        );
+        assert_eq!(
+            child.extended_public_key().to_base58(false),
+            "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw"
+        );
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        let phrase = "equip will roof matter pink blind book anxiety banner elbow sun young";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        let seed = Seed::new(&mnemonic, "");
+        let master = ExtendedSecretKey::try_from(seed.as_bytes()).unwrap();
+
+        assert_eq!(
+            master.to_base58(false),
+            "xprv9s21ZrQH143K49QgrAgAVELf6ue2tZNHYUc7yfj8JGZY9SpZ38u8EfhWi85GsA6grUeB36wXrbNTkjX9EfGP1ybbPRG4sdP2EPfY1SZ2BF5"
+        );
+
+        let path = BIP44Path::from_string("m/44'/1'/0/0/1").unwrap();
+        let esk = master.derive_bip44(&path).unwrap();
+        assert_eq!(esk.depth(), 5);
+        assert_eq!(esk.child_number(), 1);
+
+        let tprv = esk.to_base58(true);
+        assert!(tprv.starts_with("tprv"));
+        assert!(is_testnet_extended_key(&tprv).unwrap());
+
+        let imported = ExtendedSecretKey::from_base58(&tprv).unwrap();
+        assert_eq!(encode(imported.secret_key()), encode(esk.secret_key()));
+        assert_eq!(encode(imported.chain_code()), encode(esk.chain_code()));
+        assert_eq!(imported.depth(), esk.depth());
+        assert_eq!(imported.parent_fingerprint(), esk.parent_fingerprint());
+        assert_eq!(imported.to_base58(true), tprv);
+
+        let xpub = esk.extended_public_key().to_base58(false);
+        assert!(!is_testnet_extended_key(&xpub).unwrap());
+
+        let imported = ExtendedPublicKey::from_base58(&xpub).unwrap();
+        assert_eq!(
+            encode(&imported.public_key()[..]),
+            encode(&esk.public_key()[..])
+        );
+        assert_eq!(imported.to_base58(false), xpub);
+
+        assert!(ExtendedSecretKey::from_base58(&xpub).is_err());
+        assert!(ExtendedPublicKey::from_base58(&tprv).is_err());
+    }
+
     #[test]
     fn create_derive_path() {
         let path_string = "m/44'/461'/0/0/0";
@@ -0,0 +1,687 @@
+use std::convert::TryFrom;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use aes_gcm::aead::{generic_array::GenericArray, Aead, NewAead, Payload};
+use aes_gcm::Aes256Gcm;
+use chacha20poly1305::XChaCha20Poly1305;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+use crate::error::SignerError;
+use crate::{key_recover, key_recover_bls, ExtendedKey, Mnemonic, PrivateKey, PublicKey};
+
+/// Version of the keystore document format
+pub const KEYSTORE_VERSION: u32 = 1;
+
+const KDF_SCRYPT: &str = "scrypt";
+const DERIVED_KEY_SIZE: usize = 32;
+const SALT_SIZE: usize = 32;
+const ID_SIZE: usize = 16;
+const AES_GCM_NONCE_SIZE: usize = 12;
+const XCHACHA20_NONCE_SIZE: usize = 24;
+const KEYSTORE_FILE_EXTENSION: &str = "json";
+
+/// Kind of secret stored in a keystore document
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyType {
+    Secp256k1,
+    BLS,
+    Mnemonic,
+}
+
+impl KeyType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            KeyType::Secp256k1 => "secp256k1",
+            KeyType::BLS => "bls",
+            KeyType::Mnemonic => "mnemonic",
+        }
+    }
+}
+
+/// Authenticated cipher used to encrypt the secret
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cipher {
+    #[serde(rename = "aes-256-gcm")]
+    Aes256Gcm,
+    #[serde(rename = "xchacha20-poly1305")]
+    XChaCha20Poly1305,
+}
+
+impl Cipher {
+    fn nonce_size(&self) -> usize {
+        match self {
+            Cipher::Aes256Gcm => AES_GCM_NONCE_SIZE,
+            Cipher::XChaCha20Poly1305 => XCHACHA20_NONCE_SIZE,
+        }
+    }
+}
+
+/// Encryption options (cipher and scrypt cost parameters)
+#[derive(Debug, Clone, Copy)]
+pub struct KeystoreOptions {
+    pub cipher: Cipher,
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+}
+
+impl Default for KeystoreOptions {
+    fn default() -> Self {
+        KeystoreOptions {
+            cipher: Cipher::Aes256Gcm,
+            log_n: 18,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct KdfParams {
+    pub n: u64,
+    pub r: u32,
+    pub p: u32,
+    pub dklen: usize,
+    pub salt: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CipherParams {
+    pub nonce: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct KeystoreCrypto {
+    pub cipher: Cipher,
+    pub cipherparams: CipherParams,
+    pub ciphertext: String,
+    pub kdf: String,
+    pub kdfparams: KdfParams,
+}
+
+/// Versioned keystore document holding an encrypted private key or mnemonic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct EncryptedKey {
+    pub version: u32,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub address: Option<String>,
+    pub key_type: KeyType,
+    pub crypto: KeystoreCrypto,
+}
+
+/// Secret recovered from a keystore document
+#[derive(Debug)]
+pub enum DecryptedKey {
+    Key(ExtendedKey),
+    Mnemonic(Mnemonic),
+}
+
+impl EncryptedKey {
+    /// Encrypt an extended key under a passphrase
+    ///
+    /// # Arguments
+    ///
+    /// * `extended_key` - An `ExtendedKey` (secp256k1 or BLS)
+    /// * `passphrase` - Passphrase used to derive the encryption key
+    /// * `options` - Cipher and scrypt parameters
+    ///
+    pub fn encrypt_key(
+        extended_key: &ExtendedKey,
+        passphrase: &str,
+        options: &KeystoreOptions,
+    ) -> Result<Self, SignerError> {
+        let key_type = match extended_key.public_key {
+            PublicKey::PublicKeySECP256K1(_) => KeyType::Secp256k1,
+            PublicKey::BLSPublicKey(_) => KeyType::BLS,
+        };
+
+        EncryptedKey::encrypt(
+            &extended_key.private_key.0,
+            key_type,
+            Some(extended_key.address.clone()),
+            passphrase,
+            options,
+        )
+    }
+
+    /// Encrypt a mnemonic under a passphrase
+    ///
+    /// # Arguments
+    ///
+    /// * `mnemonic` - A `Mnemonic`
+    /// * `passphrase` - Passphrase used to derive the encryption key
+    /// * `options` - Cipher and scrypt parameters
+    ///
+    pub fn encrypt_mnemonic(
+        mnemonic: &Mnemonic,
+        passphrase: &str,
+        options: &KeystoreOptions,
+    ) -> Result<Self, SignerError> {
+        EncryptedKey::encrypt(
+            mnemonic.0.as_bytes(),
+            KeyType::Mnemonic,
+            None,
+            passphrase,
+            options,
+        )
+    }
+
+    /// Decrypt the document, the recovered key address must match the recorded one
+    pub fn decrypt(&self, passphrase: &str) -> Result<DecryptedKey, SignerError> {
+        let mut plaintext = self.decrypt_raw(passphrase)?;
+
+        let decrypted = match self.key_type {
+            KeyType::Secp256k1 | KeyType::BLS => {
+                let private_key = PrivateKey::try_from(plaintext.clone());
+                plaintext.zeroize();
+                let private_key = private_key?;
+
+                let address = self.address.as_deref().ok_or_else(|| {
+                    SignerError::GenericString("Keystore is missing the key address".to_string())
+                })?;
+                let testnet = address.starts_with('t');
+
+                let extended_key = if self.key_type == KeyType::BLS {
+                    key_recover_bls(&private_key, testnet)?
+                } else {
+                    key_recover(&private_key, testnet)?
+                };
+
+                if extended_key.address != address {
+                    return Err(SignerError::GenericString(
+                        "Keystore address does not match the decrypted key".to_string(),
+                    ));
+                }
+
+                DecryptedKey::Key(extended_key)
+            }
+            KeyType::Mnemonic => {
+                let phrase = String::from_utf8(plaintext).map_err(|err| {
+                    let mut bytes = err.into_bytes();
+                    bytes.zeroize();
+                    SignerError::GenericString("Invalid mnemonic encoding".to_string())
+                })?;
+
+                DecryptedKey::Mnemonic(Mnemonic(phrase))
+            }
+        };
+
+        Ok(decrypted)
+    }
+
+    /// Re-encrypt the document under a new passphrase, keeping its id and cost parameters
+    pub fn change_passphrase(
+        &self,
+        old_passphrase: &str,
+        new_passphrase: &str,
+    ) -> Result<Self, SignerError> {
+        let mut plaintext = self.decrypt_raw(old_passphrase)?;
+
+        let options = KeystoreOptions {
+            cipher: self.crypto.cipher,
+            log_n: log_n(self.crypto.kdfparams.n)?,
+            r: self.crypto.kdfparams.r,
+            p: self.crypto.kdfparams.p,
+        };
+
+        let aad = associated_data(KEYSTORE_VERSION, self.key_type, &self.address);
+        let crypto = encrypt_payload(&plaintext, new_passphrase, &aad, &options);
+        plaintext.zeroize();
+
+        Ok(EncryptedKey {
+            version: KEYSTORE_VERSION,
+            id: self.id.clone(),
+            address: self.address.clone(),
+            key_type: self.key_type,
+            crypto: crypto?,
+        })
+    }
+
+    fn encrypt(
+        plaintext: &[u8],
+        key_type: KeyType,
+        address: Option<String>,
+        passphrase: &str,
+        options: &KeystoreOptions,
+    ) -> Result<Self, SignerError> {
+        let aad = associated_data(KEYSTORE_VERSION, key_type, &address);
+        let crypto = encrypt_payload(plaintext, passphrase, &aad, options)?;
+
+        Ok(EncryptedKey {
+            version: KEYSTORE_VERSION,
+            id: hex::encode(random_bytes(ID_SIZE)?),
+            address,
+            key_type,
+            crypto,
+        })
+    }
+
+    fn decrypt_raw(&self, passphrase: &str) -> Result<Vec<u8>, SignerError> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(SignerError::GenericString(format!(
+                "Unsupported keystore version {}",
+                self.version
+            )));
+        }
+
+        let aad = associated_data(self.version, self.key_type, &self.address);
+
+        decrypt_payload(&self.crypto, passphrase, &aad)
+    }
+}
+
+/// Directory of keystore documents, one `<id>.json` file per key
+pub struct Keystore {
+    path: PathBuf,
+}
+
+impl Keystore {
+    /// Open (and create if needed) a keystore directory
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, SignerError> {
+        create_private_dir(path.as_ref())?;
+
+        Ok(Keystore {
+            path: path.as_ref().to_path_buf(),
+        })
+    }
+
+    /// Write a keystore document, replacing any document with the same id
+    pub fn store(&self, encrypted_key: &EncryptedKey) -> Result<PathBuf, SignerError> {
+        let file_path = self.file_path(&encrypted_key.id)?;
+        let tmp_path = file_path.with_extension("tmp");
+
+        let content = serde_json::to_vec_pretty(encrypted_key)
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+        // Left over by an interrupted write
+        if let Err(err) = fs::remove_file(&tmp_path) {
+            if err.kind() != io::ErrorKind::NotFound {
+                return Err(err.into());
+            }
+        }
+
+        let mut file = create_private_file(&tmp_path)?;
+        let written = file
+            .write_all(&content)
+            .and_then(|_| file.sync_all())
+            .and_then(|_| fs::rename(&tmp_path, &file_path));
+
+        if let Err(err) = written {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(err.into());
+        }
+
+        Ok(file_path)
+    }
+
+    /// Read the keystore document with the given id
+    pub fn load(&self, id: &str) -> Result<EncryptedKey, SignerError> {
+        let content = fs::read(self.file_path(id)?)?;
+
+        serde_json::from_slice(&content).map_err(|err| SignerError::GenericString(err.to_string()))
+    }
+
+    /// Read every keystore document of the directory (sorted by id)
+    pub fn list(&self) -> Result<Vec<EncryptedKey>, SignerError> {
+        let mut keys = Vec::new();
+
+        for entry in fs::read_dir(&self.path)? {
+            let path = entry?.path();
+
+            if path.extension().and_then(|ext| ext.to_str()) != Some(KEYSTORE_FILE_EXTENSION) {
+                continue;
+            }
+
+            let content = fs::read(&path)?;
+            let encrypted_key: EncryptedKey = serde_json::from_slice(&content)
+                .map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+            keys.push(encrypted_key);
+        }
+
+        keys.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Ok(keys)
+    }
+
+    /// Re-encrypt a stored document under a new passphrase
+    pub fn change_passphrase(
+        &self,
+        id: &str,
+        old_passphrase: &str,
+        new_passphrase: &str,
+    ) -> Result<(), SignerError> {
+        let encrypted_key = self
+            .load(id)?
+            .change_passphrase(old_passphrase, new_passphrase)?;
+
+        self.store(&encrypted_key)?;
+
+        Ok(())
+    }
+
+    fn file_path(&self, id: &str) -> Result<PathBuf, SignerError> {
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(SignerError::GenericString(format!(
+                "Invalid keystore id `{}`",
+                id
+            )));
+        }
+
+        Ok(self.path.join(id).with_extension(KEYSTORE_FILE_EXTENSION))
+    }
+}
+
+#[cfg(unix)]
+fn create_private_dir(path: &Path) -> Result<(), SignerError> {
+    use std::os::unix::fs::DirBuilderExt;
+
+    fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(path)?;
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn create_private_dir(path: &Path) -> Result<(), SignerError> {
+    fs::create_dir_all(path)?;
+
+    Ok(())
+}
+
+// The file is private from its creation and never follows an existing path
+#[cfg(unix)]
+fn create_private_file(path: &Path) -> Result<fs::File, SignerError> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    Ok(fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)?)
+}
+
+#[cfg(not(unix))]
+fn create_private_file(path: &Path) -> Result<fs::File, SignerError> {
+    Ok(fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?)
+}
+
+fn associated_data(version: u32, key_type: KeyType, address: &Option<String>) -> Vec<u8> {
+    format!(
+        "{}:{}:{}",
+        version,
+        key_type.as_str(),
+        address.as_deref().unwrap_or("")
+    )
+    .into_bytes()
+}
+
+fn random_bytes(size: usize) -> Result<Vec<u8>, SignerError> {
+    let mut bytes = vec![0u8; size];
+    getrandom::getrandom(&mut bytes).map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+    Ok(bytes)
+}
+
+fn log_n(n: u64) -> Result<u8, SignerError> {
+    if n < 2 || !n.is_power_of_two() {
+        return Err(SignerError::GenericString(
+            "Invalid scrypt cost parameter".to_string(),
+        ));
+    }
+
+    Ok(n.trailing_zeros() as u8)
+}
+
+fn derive_key(
+    passphrase: &str,
+    salt: &[u8],
+    log_n: u8,
+    r: u32,
+    p: u32,
+) -> Result<[u8; DERIVED_KEY_SIZE], SignerError> {
+    let params = scrypt::ScryptParams::new(log_n, r, p)
+        .map_err(|_| SignerError::GenericString("Invalid scrypt parameters".to_string()))?;
+
+    let mut derived_key = [0u8; DERIVED_KEY_SIZE];
+    scrypt::scrypt(passphrase.as_bytes(), salt, &params, &mut derived_key)
+        .map_err(|_| SignerError::GenericString("Invalid scrypt output length".to_string()))?;
+
+    Ok(derived_key)
+}
+
+fn encrypt_payload(
+    plaintext: &[u8],
+    passphrase: &str,
+    aad: &[u8],
+    options: &KeystoreOptions,
+) -> Result<KeystoreCrypto, SignerError> {
+    let salt = random_bytes(SALT_SIZE)?;
+    let nonce = random_bytes(options.cipher.nonce_size())?;
+
+    let mut derived_key = derive_key(passphrase, &salt, options.log_n, options.r, options.p)?;
+    let payload = Payload {
+        msg: plaintext,
+        aad,
+    };
+
+    let ciphertext = match options.cipher {
+        Cipher::Aes256Gcm => Aes256Gcm::new(GenericArray::from_slice(&derived_key))
+            .encrypt(GenericArray::from_slice(&nonce), payload),
+        Cipher::XChaCha20Poly1305 => XChaCha20Poly1305::new(GenericArray::from_slice(&derived_key))
+            .encrypt(GenericArray::from_slice(&nonce), payload),
+    };
+    derived_key.zeroize();
+
+    let ciphertext = ciphertext
+        .map_err(|_| SignerError::GenericString("Cannot encrypt keystore".to_string()))?;
+
+    Ok(KeystoreCrypto {
+        cipher: options.cipher,
+        cipherparams: CipherParams {
+            nonce: hex::encode(&nonce),
+        },
+        ciphertext: hex::encode(&ciphertext),
+        kdf: KDF_SCRYPT.to_string(),
+        kdfparams: KdfParams {
+            n: 1 << options.log_n,
+            r: options.r,
+            p: options.p,
+            dklen: DERIVED_KEY_SIZE,
+            salt: hex::encode(&salt),
+        },
+    })
+}
+
+fn decrypt_payload(
+    crypto: &KeystoreCrypto,
+    passphrase: &str,
+    aad: &[u8],
+) -> Result<Vec<u8>, SignerError> {
+    if crypto.kdf != KDF_SCRYPT {
+        return Err(SignerError::GenericString(format!(
+            "Unsupported key derivation function `{}`",
+            crypto.kdf
+        )));
+    }
+
+    if crypto.kdfparams.dklen != DERIVED_KEY_SIZE {
+        return Err(SignerError::GenericString(
+            "Unsupported derived key length".to_string(),
+        ));
+    }
+
+    let salt = hex::decode(&crypto.kdfparams.salt)?;
+    let nonce = hex::decode(&crypto.cipherparams.nonce)?;
+    let ciphertext = hex::decode(&crypto.ciphertext)?;
+
+    if nonce.len() != crypto.cipher.nonce_size() {
+        return Err(SignerError::GenericString(
+            "Invalid cipher nonce length".to_string(),
+        ));
+    }
+
+    let mut derived_key = derive_key(
+        passphrase,
+        &salt,
+        log_n(crypto.kdfparams.n)?,
+        crypto.kdfparams.r,
+        crypto.kdfparams.p,
+    )?;
+    let payload = Payload {
+        msg: &ciphertext,
+        aad,
+    };
+
+    let plaintext = match crypto.cipher {
+        Cipher::Aes256Gcm => Aes256Gcm::new(GenericArray::from_slice(&derived_key))
+            .decrypt(GenericArray::from_slice(&nonce), payload),
+        Cipher::XChaCha20Poly1305 => XChaCha20Poly1305::new(GenericArray::from_slice(&derived_key))
+            .decrypt(GenericArray::from_slice(&nonce), payload),
+    };
+    derived_key.zeroize();
+
+    plaintext.map_err(|_| {
+        SignerError::GenericString(
+            "Cannot decrypt keystore (wrong passphrase or corrupted data)".to_string(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::keystore::{Cipher, DecryptedKey, EncryptedKey, Keystore, KeystoreOptions};
+    use crate::{key_recover, Mnemonic, PrivateKey};
+    use std::convert::TryFrom;
+
+    const PRIVATE_KEY: &str = "8VcW07ADswS4BV2cxi5rnIadVsyTDDhY1NfDH19T8Uo=";
+    const MNEMONIC: &str = "equip will roof matter pink blind book anxiety banner elbow sun young";
+
+    fn light_options(cipher: Cipher) -> KeystoreOptions {
+        KeystoreOptions {
+            cipher,
+            log_n: 10,
+            r: 8,
+            p: 1,
+        }
+    }
+
+    #[test]
+    fn encrypt_decrypt_key() {
+        let private_key = PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap();
+        let extended_key = key_recover(&private_key, false).unwrap();
+
+        for cipher in &[Cipher::Aes256Gcm, Cipher::XChaCha20Poly1305] {
+            let encrypted_key =
+                EncryptedKey::encrypt_key(&extended_key, "passphrase", &light_options(*cipher))
+                    .unwrap();
+
+            assert_eq!(encrypted_key.address, Some(extended_key.address.clone()));
+
+            match encrypted_key.decrypt("passphrase").unwrap() {
+                DecryptedKey::Key(decrypted) => {
+                    assert_eq!(decrypted.private_key.0, extended_key.private_key.0);
+                    assert_eq!(decrypted.address, extended_key.address);
+                }
+                DecryptedKey::Mnemonic(_) => panic!("expected a key"),
+            }
+
+            assert!(encrypted_key.decrypt("wrong passphrase").is_err());
+        }
+    }
+
+    #[test]
+    fn tampered_address_fails() {
+        let private_key = PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap();
+        let extended_key = key_recover(&private_key, false).unwrap();
+
+        let mut encrypted_key = EncryptedKey::encrypt_key(
+            &extended_key,
+            "passphrase",
+            &light_options(Cipher::Aes256Gcm),
+        )
+        .unwrap();
+        encrypted_key.address = Some("f1rovwtiuo5ncslpmpjftzu5akswbgsgighjazxoi".to_string());
+
+        assert!(encrypted_key.decrypt("passphrase").is_err());
+    }
+
+    #[test]
+    fn store_load_list() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = Keystore::open(dir.path()).unwrap();
+
+        let private_key = PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap();
+        let extended_key = key_recover(&private_key, true).unwrap();
+        let options = light_options(Cipher::XChaCha20Poly1305);
+
+        let encrypted_key = EncryptedKey::encrypt_key(&extended_key, "old", &options).unwrap();
+        let encrypted_mnemonic =
+            EncryptedKey::encrypt_mnemonic(&Mnemonic(MNEMONIC.to_string()), "old", &options)
+                .unwrap();
+
+        keystore.store(&encrypted_key).unwrap();
+        keystore.store(&encrypted_mnemonic).unwrap();
+
+        let keys = keystore.list().unwrap();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.contains(&encrypted_key));
+        assert!(keys.contains(&encrypted_mnemonic));
+
+        keystore
+            .change_passphrase(&encrypted_mnemonic.id, "old", "new")
+            .unwrap();
+
+        let loaded = keystore.load(&encrypted_mnemonic.id).unwrap();
+        assert!(loaded.decrypt("old").is_err());
+        match loaded.decrypt("new").unwrap() {
+            DecryptedKey::Mnemonic(mnemonic) => assert_eq!(mnemonic.0, MNEMONIC),
+            DecryptedKey::Key(_) => panic!("expected a mnemonic"),
+        }
+
+        assert!(keystore.load("../wallet").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn private_entries() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keystore");
+        let keystore = Keystore::open(&path).unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o700);
+
+        let private_key = PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap();
+        let extended_key = key_recover(&private_key, false).unwrap();
+        let encrypted_key = EncryptedKey::encrypt_key(
+            &extended_key,
+            "passphrase",
+            &light_options(Cipher::Aes256Gcm),
+        )
+        .unwrap();
+
+        // A temporary file left over by an interrupted write doesn't block the next one
+        let tmp_path = path.join(&encrypted_key.id).with_extension("tmp");
+        std::fs::write(&tmp_path, b"partial").unwrap();
+        let file_path = keystore.store(&encrypted_key).unwrap();
+
+        let mode = std::fs::metadata(&file_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        let entries: Vec<_> = std::fs::read_dir(&path).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::convert::TryFrom;
 use std::convert::TryInto;
+use std::fmt;
 use std::str::FromStr;
 
 use bip39::{Language, MnemonicType, Seed};
@@ -10,35 +11,88 @@ use forest_address::{Address, BLSPublicKey, Network, Protocol};
 use forest_cid::{multihash::MultihashDigest, Cid, Code::Identity};
 use forest_encoding::blake2b_256;
 use forest_encoding::{from_slice, to_vec};
-use forest_message::{SignedMessage, UnsignedMessage};
-use num_bigint_chainsafe::BigInt;
+use forest_message::{Message as _, SignedMessage, UnsignedMessage};
 use num_traits::FromPrimitive;
+use rand_core::{CryptoRng, OsRng, RngCore};
 use rayon::prelude::*;
 use secp256k1::util::{
     COMPRESSED_PUBLIC_KEY_SIZE, FULL_PUBLIC_KEY_SIZE, SECRET_KEY_SIZE, SIGNATURE_SIZE,
 };
-use secp256k1::{recover, sign, verify, Message, RecoveryId};
-use zx_bip44::BIP44Path;
+use secp256k1::{recover, verify, Message, RecoveryId};
+use serde::{de, Deserialize, Deserializer, Serializer};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
 
 use extras::{multisig, paych, ExecParams, MethodInit, INIT_ACTOR_ADDR};
 
+use crate::address::{AddressFormat, AddressInfo, ParsedAddress};
 use crate::api::{
-    MessageParams, MessageTx, MessageTxAPI, MessageTxNetwork, SignatureAPI, SignedMessageAPI,
+    BLSAggregateAPI, LotusKeyInfo, LotusKeyType, MessageParams, MessageTx, MessageTxAPI,
+    MessageTxNetwork, MessageValidationAPI, SigTypes, SignatureAPI, SignedMessageAPI,
     UnsignedMessageAPI,
 };
+use crate::backend::{ensure_signer_address, PrivateKeySigner, Signer};
+use crate::bls_key::{is_testnet_bls_path, parse_bls_path, BLSSecretKey};
+use crate::delegated_address::DelegatedAddress;
+use crate::derivation_path::DerivationPath;
+use crate::discovery::{discover_accounts, ChainBackend, DiscoveredAccount, DiscoveryOptions};
 use crate::error::SignerError;
-use crate::extended_key::ExtendedSecretKey;
+use crate::extended_key::{is_testnet_extended_key, ExtendedPublicKey, ExtendedSecretKey};
+use crate::mnemonic::MnemonicValidation;
 use crate::signature::{Signature, SignatureBLS, SignatureSECP256K1};
+use crate::token::FilAmount;
 
+pub mod address;
 pub mod api;
+pub mod backend;
+pub mod bls_key;
+pub mod delegated_address;
+pub mod derivation_path;
+pub mod discovery;
 pub mod error;
 pub mod extended_key;
+pub mod keystore;
+pub mod lotus_keystore;
+pub mod message;
+pub mod mnemonic;
 pub mod signature;
+pub mod token;
 pub mod utils;
 
+/// Placeholder printed instead of a secret
+const REDACTED: &str = "<redacted>";
+
 /// Mnemonic string
+///
+/// The words are wiped from memory on drop and never printed. It serializes as the plain phrase.
+#[derive(Zeroize)]
+#[zeroize(drop)]
 pub struct Mnemonic(pub String);
 
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mnemonic({})", REDACTED)
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl serde::Serialize for Mnemonic {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Mnemonic {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Mnemonic(String::deserialize(deserializer)?))
+    }
+}
+
 /// CBOR message in a buffer
 pub struct CborBuffer(pub Vec<u8>);
 
@@ -53,8 +107,40 @@ pub const SIGNATURE_RECOVERY_SIZE: usize = SIGNATURE_SIZE + 1;
 pub const BLS_PUB_LEN: usize = 48;
 
 /// Private key buffer
+///
+/// The key is wiped from memory on drop and never printed. It serializes as a base64 string.
+#[derive(Zeroize)]
+#[zeroize(drop)]
 pub struct PrivateKey(pub [u8; SECRET_KEY_SIZE]);
 
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PrivateKey({})", REDACTED)
+    }
+}
+
+impl fmt::Display for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl serde::Serialize for PrivateKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut encoded = base64::encode(&self.0);
+        let result = serializer.serialize_str(&encoded);
+        encoded.zeroize();
+
+        result
+    }
+}
+
+impl<'de> Deserialize<'de> for PrivateKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        PrivateKey::try_from(String::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
 /// Public key secp256k1 buffer
 pub struct PublicKeySECP256K1(pub [u8; FULL_PUBLIC_KEY_SIZE]);
 
@@ -76,75 +162,333 @@ impl PublicKey {
 pub struct PublicKeyCompressed(pub [u8; COMPRESSED_PUBLIC_KEY_SIZE]);
 
 /// Extended key structure
+///
+/// The private key is wiped from memory on drop and never printed. It serializes as
+/// `{"private_base64", "public_hexstring", "address"}`, the public key and the address are
+/// checked against the private key when deserializing.
 pub struct ExtendedKey {
     pub private_key: PrivateKey,
     pub public_key: PublicKey,
     pub address: String,
 }
 
+impl ExtendedKey {
+    /// Ethereum (`0x`) address of a secp256k1 key, `None` for a BLS key
+    pub fn eth_address(&self) -> Result<Option<String>, SignerError> {
+        match &self.public_key {
+            PublicKey::PublicKeySECP256K1(public_key) => Ok(Some(
+                delegated_address::eth_address_from_public_key(&public_key.0)?,
+            )),
+            PublicKey::BLSPublicKey(_) => Ok(None),
+        }
+    }
+
+    /// Delegated (f410) address of a secp256k1 key on the network of `address`, `None` for a BLS key
+    pub fn delegated_address(&self) -> Result<Option<String>, SignerError> {
+        match &self.public_key {
+            PublicKey::PublicKeySECP256K1(public_key) => Ok(Some(
+                DelegatedAddress::from_public_key(&public_key.0, self.address.starts_with('t'))?
+                    .to_string(),
+            )),
+            PublicKey::BLSPublicKey(_) => Ok(None),
+        }
+    }
+}
+
+impl fmt::Debug for ExtendedKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedKey")
+            .field("private_key", &self.private_key)
+            .field("public_key", &hex::encode(self.public_key.to_vec()))
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+impl fmt::Display for ExtendedKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.address)
+    }
+}
+
+#[derive(serde::Serialize, Deserialize)]
+struct SerializedExtendedKey {
+    private_base64: String,
+    public_hexstring: String,
+    address: String,
+}
+
+impl Drop for SerializedExtendedKey {
+    fn drop(&mut self) {
+        self.private_base64.zeroize();
+    }
+}
+
+impl serde::Serialize for ExtendedKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let serialized = SerializedExtendedKey {
+            private_base64: base64::encode(&self.private_key.0),
+            public_hexstring: hex::encode(self.public_key.to_vec()),
+            address: self.address.clone(),
+        };
+
+        serde::Serialize::serialize(&serialized, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ExtendedKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let serialized = SerializedExtendedKey::deserialize(deserializer)?;
+
+        let private_key =
+            PrivateKey::try_from(serialized.private_base64.clone()).map_err(de::Error::custom)?;
+        let testnet = serialized.address.starts_with('t');
+
+        let extended_key = match serialized.address.as_bytes().get(1) {
+            Some(b'1') => key_recover(&private_key, testnet),
+            Some(b'3') => key_recover_bls(&private_key, testnet),
+            _ => Err(SignerError::GenericString(
+                "Unsupported address protocol".to_string(),
+            )),
+        }
+        .map_err(de::Error::custom)?;
+
+        if extended_key.address != serialized.address
+            || hex::encode(extended_key.public_key.to_vec()) != serialized.public_hexstring
+        {
+            return Err(de::Error::custom(
+                "Public key or address does not match the private key",
+            ));
+        }
+
+        Ok(extended_key)
+    }
+}
+
 #[cfg(feature = "with-ffi-support")]
 ffi_support::implement_into_ffi_by_pointer!(ExtendedKey);
 
+/// Watch-only key structure (no private key)
+pub struct WatchOnlyKey {
+    pub public_key: PublicKey,
+    pub address: String,
+}
+
 impl TryFrom<String> for PrivateKey {
     type Error = SignerError;
 
-    fn try_from(s: String) -> Result<PrivateKey, Self::Error> {
-        let v = base64::decode(&s)?;
+    fn try_from(mut s: String) -> Result<PrivateKey, Self::Error> {
+        let v = base64::decode(&s);
+        s.zeroize();
 
-        PrivateKey::try_from(v)
+        PrivateKey::try_from(v?)
     }
 }
 
 impl TryFrom<Vec<u8>> for PrivateKey {
     type Error = SignerError;
 
-    fn try_from(v: Vec<u8>) -> Result<PrivateKey, Self::Error> {
+    fn try_from(mut v: Vec<u8>) -> Result<PrivateKey, Self::Error> {
         if v.len() != SECRET_KEY_SIZE {
+            v.zeroize();
             return Err(SignerError::GenericString("Invalid Key Length".to_string()));
         }
         let mut sk = PrivateKey {
             0: [0; SECRET_KEY_SIZE],
         };
         sk.0.copy_from_slice(&v[..SECRET_KEY_SIZE]);
+        v.zeroize();
         Ok(sk)
     }
 }
 
+/// Word count of the mnemonic generated by default
+pub const DEFAULT_MNEMONIC_WORD_COUNT: usize = 24;
+/// Language code of the mnemonic generated by default
+pub const DEFAULT_MNEMONIC_LANGUAGE_CODE: &str = "en";
+
 /// Generates a random mnemonic (English - 24 words)
 pub fn key_generate_mnemonic() -> Result<Mnemonic, SignerError> {
-    let mnemonic = bip39::Mnemonic::new(MnemonicType::Words24, Language::English);
-    Ok(Mnemonic(mnemonic.to_string()))
+    key_generate_mnemonic_with_options(DEFAULT_MNEMONIC_WORD_COUNT, DEFAULT_MNEMONIC_LANGUAGE_CODE)
+}
+
+/// Generates a random mnemonic using the OS random number generator
+///
+/// # Arguments
+///
+/// * `word_count` - number of words (12, 15, 18, 21 or 24)
+/// * `language_code` - language of the words (e.g `en`, `fr`, `ja`, `zh-hans`)
+///
+pub fn key_generate_mnemonic_with_options(
+    word_count: usize,
+    language_code: &str,
+) -> Result<Mnemonic, SignerError> {
+    key_generate_mnemonic_from_rng(&mut OsRng, word_count, language_code)
 }
 
-fn derive_extended_secret_key(seed: &[u8], path: &str) -> Result<ExtendedSecretKey, SignerError> {
+/// Generates a mnemonic drawing its entropy from the given random number generator
+///
+/// A seeded generator makes the output deterministic, which is only meant for tests.
+///
+/// # Arguments
+///
+/// * `rng` - cryptographically secure random number generator
+/// * `word_count` - number of words (12, 15, 18, 21 or 24)
+/// * `language_code` - language of the words (e.g `en`, `fr`, `ja`, `zh-hans`)
+///
+pub fn key_generate_mnemonic_from_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    word_count: usize,
+    language_code: &str,
+) -> Result<Mnemonic, SignerError> {
+    let mnemonic_type = mnemonic_type(word_count)?;
+
+    let mut entropy = vec![0u8; mnemonic_type.entropy_bits() / 8];
+    rng.try_fill_bytes(&mut entropy)
+        .map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+    let mnemonic = key_generate_mnemonic_from_entropy(&entropy, language_code);
+    entropy.zeroize();
+
+    mnemonic
+}
+
+/// Generates the mnemonic encoding the given entropy
+///
+/// # Arguments
+///
+/// * `entropy` - 16, 20, 24, 28 or 32 bytes giving respectively a 12, 15, 18, 21 or 24-words mnemonic
+/// * `language_code` - language of the words (e.g `en`, `fr`, `ja`, `zh-hans`)
+///
+pub fn key_generate_mnemonic_from_entropy(
+    entropy: &[u8],
+    language_code: &str,
+) -> Result<Mnemonic, SignerError> {
+    let mnemonic = bip39::Mnemonic::from_entropy(entropy, mnemonic_language(language_code)?)
+        .map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+    Ok(Mnemonic(mnemonic.phrase().to_string()))
+}
+
+/// Returns the mnemonic entropy derived from a sequence of dice rolls
+///
+/// The entropy is the SHA-256 hash of the rolls truncated to the size required by `word_count`.
+/// Enough rolls must be given to cover that size (e.g 50 rolls for 12 words, 100 rolls for 24 words).
+///
+/// # Arguments
+///
+/// * `rolls` - dice rolls as a string of digits between 1 and 6, whitespaces are ignored
+/// * `word_count` - number of words of the mnemonic (12, 15, 18, 21 or 24)
+///
+pub fn mnemonic_entropy_from_dice(rolls: &str, word_count: usize) -> Result<Vec<u8>, SignerError> {
+    let mnemonic_type = mnemonic_type(word_count)?;
+
+    let rolls: String = rolls.chars().filter(|c| !c.is_whitespace()).collect();
+
+    if let Some(c) = rolls.chars().find(|c| !('1'..='6').contains(c)) {
+        return Err(SignerError::GenericString(format!(
+            "Invalid dice roll `{}`",
+            c
+        )));
+    }
+
+    // Each roll of a six-sided die gives log2(6) bits of entropy
+    let entropy_bits = mnemonic_type.entropy_bits();
+    let min_rolls = (entropy_bits as f64 / 6f64.log2()).ceil() as usize;
+
+    if rolls.len() < min_rolls {
+        return Err(SignerError::GenericString(format!(
+            "{} dice rolls required for a {}-words mnemonic, got {}",
+            min_rolls,
+            word_count,
+            rolls.len()
+        )));
+    }
+
+    let digest = Sha256::digest(rolls.as_bytes());
+
+    Ok(digest[..entropy_bits / 8].to_vec())
+}
+
+/// Validate a mnemonic and report what is wrong with it
+///
+/// Invalid words come with the closest wordlist words. The entropy and the seed are returned
+/// for a valid mnemonic.
+///
+/// # Arguments
+///
+/// * `mnemonic` - the mnemonic words
+/// * `password` - password used to compute the seed
+/// * `language_code` - language of the mnemonic, detected from the words if `None`
+///
+pub fn mnemonic_validate(
+    mnemonic: &str,
+    password: &str,
+    language_code: Option<&str>,
+) -> Result<MnemonicValidation, SignerError> {
+    MnemonicValidation::validate(mnemonic, password, language_code)
+}
+
+fn mnemonic_type(word_count: usize) -> Result<MnemonicType, SignerError> {
+    MnemonicType::for_word_count(word_count).map_err(|_| {
+        SignerError::GenericString(format!(
+            "Invalid mnemonic word count {} (expected 12, 15, 18, 21 or 24)",
+            word_count
+        ))
+    })
+}
+
+fn mnemonic_language(language_code: &str) -> Result<Language, SignerError> {
+    Language::from_language_code(language_code)
+        .ok_or_else(|| SignerError::GenericString("Unknown language code".to_string()))
+}
+
+fn derive_extended_secret_key(
+    seed: &[u8],
+    path: &DerivationPath,
+) -> Result<ExtendedSecretKey, SignerError> {
     let master = ExtendedSecretKey::try_from(seed)?;
-    let bip44_path = BIP44Path::from_string(path)?;
-    let esk = master.derive_bip44(&bip44_path)?;
+    let esk = master.derive_path(path)?;
 
     Ok(esk)
 }
 
+fn seed_from_mnemonic(
+    mnemonic: &str,
+    password: &str,
+    language_code: &str,
+) -> Result<Seed, SignerError> {
+    let lang = mnemonic_language(language_code)?;
+
+    let mnemonic = bip39::Mnemonic::from_phrase(&mnemonic, lang)
+        .map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+    Ok(Seed::new(&mnemonic, password))
+}
+
 fn derive_extended_secret_key_from_mnemonic(
     mnemonic: &str,
-    path: &str,
+    path: &DerivationPath,
     password: &str,
     language_code: &str,
 ) -> Result<ExtendedSecretKey, SignerError> {
-    let lang = Language::from_language_code(language_code);
+    let seed = seed_from_mnemonic(mnemonic, password, language_code)?;
 
-    match lang {
-        Some(l) => {
-            let mnemonic = bip39::Mnemonic::from_phrase(&mnemonic, l)
-                .map_err(|err| SignerError::GenericString(err.to_string()))?;
+    derive_extended_secret_key(seed.as_bytes(), path)
+}
 
-            let seed = Seed::new(&mnemonic, password);
+// Keys are derived from the master key, a relative path is only meaningful from an extended key
+fn absolute_derivation_path(path: &str) -> Result<DerivationPath, SignerError> {
+    let derivation_path = DerivationPath::from_str(path)?;
 
-            derive_extended_secret_key(seed.as_bytes(), path)
-        }
-        None => Err(SignerError::GenericString(
-            "Unknown language code".to_string(),
-        )),
+    if !derivation_path.is_absolute() {
+        return Err(SignerError::InvalidDerivationPath(format!(
+            "`{}` must start with `m/`",
+            path
+        )));
     }
+
+    Ok(derivation_path)
 }
 
 /// Returns a public key, private key and address given a mnemonic, derivation path and a password
@@ -160,14 +504,18 @@ pub fn key_derive(
     password: &str,
     language_code: &str,
 ) -> Result<ExtendedKey, SignerError> {
-    let esk = derive_extended_secret_key_from_mnemonic(mnemonic, path, password, language_code)?;
+    let derivation_path = absolute_derivation_path(path)?;
+    let esk = derive_extended_secret_key_from_mnemonic(
+        mnemonic,
+        &derivation_path,
+        password,
+        language_code,
+    )?;
 
     let mut address = Address::new_secp256k1(&esk.public_key().to_vec())?;
 
-    let bip44_path = BIP44Path::from_string(path)?;
-
     address.set_network(Network::Mainnet);
-    if bip44_path.is_testnet() {
+    if derivation_path.is_testnet() {
         address.set_network(Network::Testnet);
     }
 
@@ -186,15 +534,94 @@ pub fn key_derive(
 /// * `path` - A string containing a derivation path
 ///
 pub fn key_derive_from_seed(seed: &[u8], path: &str) -> Result<ExtendedKey, SignerError> {
-    let esk = derive_extended_secret_key(seed, path)?;
+    let derivation_path = absolute_derivation_path(path)?;
+    let esk = derive_extended_secret_key(seed, &derivation_path)?;
 
     let mut address = Address::new_secp256k1(&esk.public_key().to_vec())?;
 
-    let bip44_path = BIP44Path::from_string(path)?;
-
     address.set_network(Network::Mainnet);
-    if bip44_path.is_testnet() {
+    if derivation_path.is_testnet() {
+        address.set_network(Network::Testnet);
+    }
+
+    Ok(ExtendedKey {
+        private_key: PrivateKey(esk.secret_key()),
+        public_key: PublicKey::PublicKeySECP256K1(PublicKeySECP256K1(esk.public_key())),
+        address: address.to_string(),
+    })
+}
+
+/// Returns the used addresses of a mnemonic following the discovery layouts and gap limit
+///
+/// # Arguments
+///
+/// * `backend` - source of the on-chain state of the addresses (e.g a Lotus node)
+/// * `mnemonic` - A string containing the mnemonic words
+/// * `password` - A string containing the password
+/// * `language_code` - language of the mnemonic (e.g `en`)
+/// * `options` - layouts, gap limit and network to scan
+///
+pub async fn key_discover<B: ChainBackend + Sync>(
+    backend: &B,
+    mnemonic: &str,
+    password: &str,
+    language_code: &str,
+    options: &DiscoveryOptions,
+) -> Result<Vec<DiscoveredAccount>, SignerError> {
+    let seed = seed_from_mnemonic(mnemonic, password, language_code)?;
+
+    discover_accounts(backend, seed.as_bytes(), options).await
+}
+
+/// Returns a public key and address given an account level extended public key and non-hardened child indexes
+///
+/// # Arguments
+///
+/// * `extended_public_key` - An `ExtendedPublicKey` (e.g derived from `m/44'/461'/0'`)
+/// * `child_indexes` - Non-hardened child indexes to derive (e.g `[0, 5]`)
+/// * `testnet` - specify the network, `true` if testnet else `false` for mainnet
+///
+pub fn key_derive_public(
+    extended_public_key: &ExtendedPublicKey,
+    child_indexes: &[u32],
+    testnet: bool,
+) -> Result<WatchOnlyKey, SignerError> {
+    let epk = extended_public_key.derive_children(child_indexes)?;
+
+    let mut address = Address::new_secp256k1(&epk.public_key().to_vec())?;
+
+    if testnet {
+        address.set_network(Network::Testnet);
+    } else {
+        address.set_network(Network::Mainnet);
+    }
+
+    Ok(WatchOnlyKey {
+        public_key: PublicKey::PublicKeySECP256K1(PublicKeySECP256K1(epk.public_key())),
+        address: address.to_string(),
+    })
+}
+
+/// Returns a public key, private key and address given a serialized extended private key and a relative derivation path
+///
+/// # Arguments
+///
+/// * `xprv` - A BIP32 Base58Check extended private key (`xprv` for mainnet, `tprv` for testnet)
+/// * `path` - A relative derivation path (e.g "0/1" or "0'/5"), an empty string uses the key itself.
+///   An absolute path (e.g "m/44'/461'/0/0/0") is accepted for master keys
+///
+pub fn key_derive_from_extended(xprv: &str, path: &str) -> Result<ExtendedKey, SignerError> {
+    let testnet = is_testnet_extended_key(xprv)?;
+
+    let esk =
+        ExtendedSecretKey::from_base58(xprv)?.derive_path(&DerivationPath::from_str(path)?)?;
+
+    let mut address = Address::new_secp256k1(&esk.public_key().to_vec())?;
+
+    if testnet {
         address.set_network(Network::Testnet);
+    } else {
+        address.set_network(Network::Mainnet);
     }
 
     Ok(ExtendedKey {
@@ -204,6 +631,25 @@ pub fn key_derive_from_seed(seed: &[u8], path: &str) -> Result<ExtendedKey, Sign
     })
 }
 
+/// Returns a public key and address given a serialized extended public key and a relative derivation path
+///
+/// # Arguments
+///
+/// * `xpub` - A BIP32 Base58Check extended public key (`xpub` for mainnet, `tpub` for testnet)
+/// * `path` - A relative non-hardened derivation path (e.g "0/1"), an empty string uses the key itself.
+///   An absolute path is accepted for master keys
+///
+pub fn key_derive_public_from_extended(
+    xpub: &str,
+    path: &str,
+) -> Result<WatchOnlyKey, SignerError> {
+    let testnet = is_testnet_extended_key(xpub)?;
+    let epk =
+        ExtendedPublicKey::from_base58(xpub)?.derive_path(&DerivationPath::from_str(path)?)?;
+
+    key_derive_public(&epk, &[], testnet)
+}
+
 /// Get extended key from private key
 ///
 /// # Arguments
@@ -229,6 +675,59 @@ pub fn key_recover(private_key: &PrivateKey, testnet: bool) -> Result<ExtendedKe
     })
 }
 
+/// Returns the delegated (f410) address of an Ethereum address
+///
+/// # Arguments
+///
+/// * `eth_address` - A `0x` prefixed Ethereum address, mixed case addresses must have a valid EIP-55 checksum
+/// * `testnet` - specify the network, `true` if testnet else `false` for mainnet
+///
+pub fn eth_address_to_delegated(eth_address: &str, testnet: bool) -> Result<String, SignerError> {
+    Ok(DelegatedAddress::from_eth_address(eth_address, testnet)?.to_string())
+}
+
+/// Returns the Ethereum address (EIP-55 checksummed) of a delegated (f410) address
+///
+/// # Arguments
+///
+/// * `address` - A f410 (or t410) address
+///
+pub fn delegated_to_eth_address(address: &str) -> Result<String, SignerError> {
+    DelegatedAddress::from_str(address)?.eth_address()
+}
+
+/// Returns the description of an address: protocol, network, payload and checksum validity
+///
+/// # Arguments
+///
+/// * `address` - A `f` or `t` prefixed address of any protocol
+///
+pub fn address_inspect(address: &str) -> Result<AddressInfo, SignerError> {
+    crate::address::inspect(address)
+}
+
+/// Re-encode an address on the other network, as bytes, or as an Ethereum address (f410 only)
+///
+/// # Arguments
+///
+/// * `address` - A `f` or `t` prefixed address with a valid checksum, or a `0x` Ethereum address
+/// * `format` - the target form
+///
+pub fn address_convert(address: &str, format: AddressFormat) -> Result<String, SignerError> {
+    crate::address::convert(address, format)
+}
+
+/// Returns the string form of an address given as bytes (protocol byte followed by the payload)
+///
+/// # Arguments
+///
+/// * `bytes` - the address bytes
+/// * `testnet` - specify the network, `true` if testnet else `false` for mainnet
+///
+pub fn address_from_bytes(bytes: &[u8], testnet: bool) -> Result<String, SignerError> {
+    ParsedAddress::from_bytes(bytes, testnet)?.encode(testnet)
+}
+
 /// Get extended key from BLS private key
 ///
 /// # Arguments
@@ -267,6 +766,105 @@ pub fn key_recover_bls(
     })
 }
 
+/// Generates a random BLS private key and returns the corresponding extended key
+///
+/// # Arguments
+///
+/// * `testnet` - specify the network, `true` if testnet else `false` for mainnet
+///
+pub fn key_generate_bls(testnet: bool) -> Result<ExtendedKey, SignerError> {
+    let mut ikm = [0u8; 32];
+    getrandom::getrandom(&mut ikm).map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+    let bls_secret_key = BLSSecretKey::from_ikm(&ikm)?;
+    ikm.zeroize();
+
+    key_recover_bls(&PrivateKey(bls_secret_key.secret_key()), testnet)
+}
+
+/// Returns a BLS public key, private key and address given a mnemonic, EIP-2334 derivation path and a password
+///
+/// # Arguments
+///
+/// * `mnemonic` - A string containing a 24-words English mnemonic
+/// * `path` - A string containing an EIP-2334 derivation path (e.g `m/12381/461/0/0`)
+/// * `password` - Password to decrypt seed, if none use and empty string (e.g "")
+pub fn key_derive_bls(
+    mnemonic: &str,
+    path: &str,
+    password: &str,
+    language_code: &str,
+) -> Result<ExtendedKey, SignerError> {
+    let seed = seed_from_mnemonic(mnemonic, password, language_code)?;
+
+    key_derive_bls_from_seed(seed.as_bytes(), path)
+}
+
+/// Returns a BLS public key, private key and address given a seed and EIP-2334 derivation path
+///
+/// # Arguments
+///
+/// * `seed` - A seed as bytes array
+/// * `path` - A string containing an EIP-2334 derivation path (e.g `m/12381/461/0/0`)
+///
+pub fn key_derive_bls_from_seed(seed: &[u8], path: &str) -> Result<ExtendedKey, SignerError> {
+    let bls_path = parse_bls_path(path)?;
+
+    let master = BLSSecretKey::from_seed(seed)?;
+    let bls_secret_key = master.derive_path(&bls_path)?;
+
+    key_recover_bls(
+        &PrivateKey(bls_secret_key.secret_key()),
+        is_testnet_bls_path(&bls_path),
+    )
+}
+
+/// Import a key exported by Lotus (`lotus wallet export`)
+///
+/// # Arguments
+///
+/// * `lotus_key` - A hex encoded Lotus KeyInfo (e.g `{"Type":"secp256k1","PrivateKey":"<base64>"}`)
+/// * `testnet` - specify the network, `true` if testnet else `false` for mainnet
+///
+pub fn key_import_lotus(lotus_key: &str, testnet: bool) -> Result<ExtendedKey, SignerError> {
+    let mut key_info_json = hex::decode(lotus_key.trim())?;
+    let key_info = serde_json::from_slice::<LotusKeyInfo>(&key_info_json);
+    key_info_json.zeroize();
+
+    let key_info = key_info.map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+    key_recover_lotus_key_info(&key_info, testnet)
+}
+
+fn key_recover_lotus_key_info(
+    key_info: &LotusKeyInfo,
+    testnet: bool,
+) -> Result<ExtendedKey, SignerError> {
+    let private_key = PrivateKey::try_from(key_info.private_key.clone())?;
+
+    match key_info.key_type {
+        LotusKeyType::Secp256k1 => key_recover(&private_key, testnet),
+        LotusKeyType::BLS => key_recover_bls(&private_key, testnet),
+    }
+}
+
+/// Export a key in the Lotus format (`lotus wallet import`)
+///
+/// # Arguments
+///
+/// * `extended_key` - An `ExtendedKey`
+///
+pub fn key_export_lotus(extended_key: &ExtendedKey) -> Result<String, SignerError> {
+    let key_info = LotusKeyInfo::from(extended_key);
+
+    let mut key_info_json =
+        serde_json::to_vec(&key_info).map_err(|err| SignerError::GenericString(err.to_string()))?;
+    let lotus_key = hex::encode(&key_info_json);
+    key_info_json.zeroize();
+
+    Ok(lotus_key)
+}
+
 /// Serialize a transaction and return a CBOR hexstring.
 ///
 /// # Arguments
@@ -304,39 +902,25 @@ pub fn transaction_parse(
     Ok(parsed_message)
 }
 
-fn transaction_sign_secp56k1_raw(
+fn transaction_sign_secp56k1_raw<S: Signer + ?Sized>(
     unsigned_message_api: &UnsignedMessageAPI,
-    private_key: &PrivateKey,
+    signer: &S,
 ) -> Result<SignatureSECP256K1, SignerError> {
     let message_cbor = transaction_serialize(unsigned_message_api)?;
 
-    let secret_key = secp256k1::SecretKey::parse_slice(&private_key.0)?;
-
     let cid_hashed = utils::get_digest(message_cbor.as_ref())?;
 
-    let message_digest = Message::parse_slice(&cid_hashed)?;
-
-    let (signature_rs, recovery_id) = sign(&message_digest, &secret_key);
-
-    let mut signature = SignatureSECP256K1 { 0: [0; 65] };
-    signature.0[..64].copy_from_slice(&signature_rs.serialize()[..]);
-    signature.0[64] = recovery_id.serialize();
-
-    Ok(signature)
+    signer.sign_digest(&cid_hashed)
 }
 
-fn transaction_sign_bls_raw(
+fn transaction_sign_bls_raw<S: Signer + ?Sized>(
     unsigned_message_api: &UnsignedMessageAPI,
-    private_key: &PrivateKey,
+    signer: &S,
 ) -> Result<SignatureBLS, SignerError> {
-    let sk = bls_signatures::PrivateKey::from_bytes(&private_key.0)?;
-
     let unsigned_message = UnsignedMessage::try_from(unsigned_message_api)?;
 
     //sign the message's signing bytes
-    let sig = sk.sign(unsigned_message.to_signing_bytes());
-
-    Ok(SignatureBLS::try_from(sig.as_bytes())?)
+    signer.sign_bytes(&unsigned_message.to_signing_bytes())
 }
 
 /// Sign a transaction and return a raw signature (RSV format).
@@ -359,10 +943,17 @@ pub fn transaction_sign_raw(
     {
         b'1' => Signature::SignatureSECP256K1(transaction_sign_secp56k1_raw(
             unsigned_message_api,
-            private_key,
+            &PrivateKeySigner::Secp256k1(private_key),
+        )?),
+        b'3' => Signature::SignatureBLS(transaction_sign_bls_raw(
+            unsigned_message_api,
+            &PrivateKeySigner::Bls(private_key),
         )?),
-        b'3' => {
-            Signature::SignatureBLS(transaction_sign_bls_raw(unsigned_message_api, private_key)?)
+        b'4' => {
+            return Err(SignerError::GenericString(
+                "Delegated (f4) addresses sign Ethereum transactions, not Filecoin messages"
+                    .to_string(),
+            ));
         }
         _ => {
             return Err(SignerError::GenericString(
@@ -374,6 +965,34 @@ pub fn transaction_sign_raw(
     Ok(signature)
 }
 
+/// Sign a transaction with a `Signer` and return a raw signature (RSV format).
+///
+/// The signer must hold the key of the message's `from` address.
+///
+/// # Arguments
+///
+/// * `unsigned_message_api` - an unsigned filecoin message
+/// * `signer` - a `Signer` (e.g a HSM or a remote process)
+///
+pub fn transaction_sign_raw_with_signer<S: Signer + ?Sized>(
+    unsigned_message_api: &UnsignedMessageAPI,
+    signer: &S,
+) -> Result<Signature, SignerError> {
+    let from = Address::from_str(&unsigned_message_api.from)?;
+
+    ensure_signer_address(signer, &from)?;
+
+    let signature = match from.protocol() {
+        Protocol::Secp256k1 => Signature::SignatureSECP256K1(transaction_sign_secp56k1_raw(
+            unsigned_message_api,
+            signer,
+        )?),
+        _ => Signature::SignatureBLS(transaction_sign_bls_raw(unsigned_message_api, signer)?),
+    };
+
+    Ok(signature)
+}
+
 /// Sign a transaction and return a signed message (message + signature).
 ///
 /// # Arguments
@@ -395,66 +1014,271 @@ pub fn transaction_sign(
     Ok(signed_message)
 }
 
-fn verify_secp256k1_signature(
-    signature: &SignatureSECP256K1,
-    cbor_buffer: &CborBuffer,
-) -> Result<bool, SignerError> {
-    let network = Network::Testnet;
+/// Sign many transactions in parallel with the same private key. Return one result per
+/// transaction, in the same order.
+///
+/// # Arguments
+///
+/// * `unsigned_messages` - the unsigned filecoin messages
+/// * `private_key` - a `PrivateKey`
+///
+pub fn transaction_sign_batch(
+    unsigned_messages: &[UnsignedMessageAPI],
+    private_key: &PrivateKey,
+) -> Vec<Result<SignedMessageAPI, SignerError>> {
+    unsigned_messages
+        .par_iter()
+        .map(|unsigned_message| transaction_sign(unsigned_message, private_key))
+        .collect()
+}
 
-    let signature_rs = secp256k1::Signature::parse_slice(&signature.0[..64])?;
-    let recovery_id = RecoveryId::parse(signature.0[64])?;
+/// Sign a transaction with a `Signer` and return a signed message (message + signature).
+///
+/// # Arguments
+///
+/// * `unsigned_message_api` - an unsigned filecoin message
+/// * `signer` - a `Signer` holding the key of the `from` address
+///
+pub fn transaction_sign_with_signer<S: Signer + ?Sized>(
+    unsigned_message: &UnsignedMessageAPI,
+    signer: &S,
+) -> Result<SignedMessageAPI, SignerError> {
+    let signature = transaction_sign_raw_with_signer(unsigned_message, signer)?;
 
-    // Should be default network here
-    // FIXME: For now only testnet
-    let tx = transaction_parse(cbor_buffer, network == Network::Testnet)?;
+    let signed_message = SignedMessageAPI {
+        message: unsigned_message.to_owned(),
+        signature: SignatureAPI::from(&signature),
+    };
 
-    // Decode the CBOR transaction hex string into CBOR transaction buffer
-    let message_digest = utils::get_digest(cbor_buffer.as_ref())?;
+    Ok(signed_message)
+}
+
+/// Check a transaction before signing it. Return the errors and warnings found.
+///
+/// # Arguments
+///
+/// * `unsigned_message` - an unsigned filecoin message
+/// * `actor_type` - the actor type of `to` (e.g "fil/5/multisig") to decode the params, if known
+///
+pub fn message_validate(
+    unsigned_message: &UnsignedMessageAPI,
+    actor_type: Option<&str>,
+) -> MessageValidationAPI {
+    message::validate(unsigned_message, actor_type)
+}
+
+fn ensure_valid_message(unsigned_message: &UnsignedMessageAPI) -> Result<(), SignerError> {
+    let validation = message_validate(unsigned_message, None);
+
+    if validation.is_valid() {
+        return Ok(());
+    }
+
+    let errors: Vec<String> = validation
+        .errors
+        .iter()
+        .map(|issue| format!("{}: {}", issue.field, issue.message))
+        .collect();
+
+    Err(SignerError::GenericString(format!(
+        "Refusing to sign an invalid message ({})",
+        errors.join("; ")
+    )))
+}
+
+/// Like `transaction_sign_raw`, but refuse to sign a message `message_validate` finds errors in.
+///
+/// # Arguments
+///
+/// * `unsigned_message_api` - an unsigned filecoin message
+/// * `private_key` - a `PrivateKey`
+///
+pub fn transaction_sign_raw_strict(
+    unsigned_message_api: &UnsignedMessageAPI,
+    private_key: &PrivateKey,
+) -> Result<Signature, SignerError> {
+    ensure_valid_message(unsigned_message_api)?;
+
+    transaction_sign_raw(unsigned_message_api, private_key)
+}
+
+/// Like `transaction_sign`, but refuse to sign a message `message_validate` finds errors in.
+///
+/// # Arguments
+///
+/// * `unsigned_message` - an unsigned filecoin message
+/// * `private_key` - a `PrivateKey`
+///
+pub fn transaction_sign_strict(
+    unsigned_message: &UnsignedMessageAPI,
+    private_key: &PrivateKey,
+) -> Result<SignedMessageAPI, SignerError> {
+    ensure_valid_message(unsigned_message)?;
+
+    transaction_sign(unsigned_message, private_key)
+}
+
+/// Like `transaction_sign_with_signer`, but refuse to sign a message `message_validate` finds
+/// errors in.
+///
+/// # Arguments
+///
+/// * `unsigned_message` - an unsigned filecoin message
+/// * `signer` - a `Signer` holding the key of the `from` address
+///
+pub fn transaction_sign_with_signer_strict<S: Signer + ?Sized>(
+    unsigned_message: &UnsignedMessageAPI,
+    signer: &S,
+) -> Result<SignedMessageAPI, SignerError> {
+    ensure_valid_message(unsigned_message)?;
+
+    transaction_sign_with_signer(unsigned_message, signer)
+}
+
+/// Sign arbitrary bytes with a `Signer`, like Lotus `WalletSign`.
+///
+/// Secp256k1 keys sign the blake2b-256 digest of the bytes, BLS keys sign the bytes.
+///
+/// # Arguments
+///
+/// * `bytes` - the bytes to sign
+/// * `signer` - a `Signer`
+/// * `prefixed` - sign the bytes behind the `utils::SIGNED_MESSAGE_PREFIX` domain separation prefix
+///
+pub fn sign_bytes_with_signer<S: Signer + ?Sized>(
+    bytes: &[u8],
+    signer: &S,
+    prefixed: bool,
+) -> Result<Signature, SignerError> {
+    let prefixed_bytes;
+    let bytes = if prefixed {
+        prefixed_bytes = utils::prefix_signed_message(bytes);
+        &prefixed_bytes[..]
+    } else {
+        bytes
+    };
+
+    let signature = match signer.address()?.protocol() {
+        Protocol::Secp256k1 => {
+            Signature::SignatureSECP256K1(signer.sign_digest(&blake2b_256(bytes))?)
+        }
+        Protocol::BLS => Signature::SignatureBLS(signer.sign_bytes(bytes)?),
+        _ => {
+            return Err(SignerError::GenericString(
+                "Signer address should be BLS or Secp256k1".to_string(),
+            ));
+        }
+    };
+
+    Ok(signature)
+}
+
+/// Sign arbitrary bytes, like Lotus `WalletSign`.
+///
+/// The protocol of `address` selects the signing scheme, the private key must match the address.
+/// Use `prefixed` for user-facing data (login challenges, proofs of ownership...) so the signature
+/// can never be replayed as a message signature.
+///
+/// # Arguments
+///
+/// * `bytes` - the bytes to sign
+/// * `address` - the secp256k1 or BLS address of the key
+/// * `private_key` - a `PrivateKey`
+/// * `prefixed` - sign the bytes behind the `utils::SIGNED_MESSAGE_PREFIX` domain separation prefix
+///
+pub fn sign_bytes(
+    bytes: &[u8],
+    address: &str,
+    private_key: &PrivateKey,
+    prefixed: bool,
+) -> Result<Signature, SignerError> {
+    let address = Address::from_str(address)?;
+
+    let signer = match address.protocol() {
+        Protocol::Secp256k1 => PrivateKeySigner::Secp256k1(private_key),
+        Protocol::BLS => PrivateKeySigner::Bls(private_key),
+        _ => {
+            return Err(SignerError::GenericString(
+                "Address should be BLS or Secp256k1".to_string(),
+            ));
+        }
+    };
+
+    ensure_signer_address(&signer, &address)?;
+
+    sign_bytes_with_signer(bytes, &signer, prefixed)
+}
+
+/// Unsigned part of a CBOR message (signed or unsigned)
+fn cbor_unsigned_message(cbor_buffer: &CborBuffer) -> Result<UnsignedMessage, SignerError> {
+    let message: MessageTx = from_slice(cbor_buffer.as_ref())?;
+
+    let unsigned_message = match message {
+        MessageTx::UnsignedMessage(unsigned_message) => unsigned_message,
+        MessageTx::SignedMessage(signed_message) => signed_message.message().clone(),
+    };
+
+    Ok(unsigned_message)
+}
+
+fn verify_secp256k1_digest(
+    signature: &SignatureSECP256K1,
+    digest: &[u8; 32],
+    from: &Address,
+) -> Result<bool, SignerError> {
+    let signature_rs = secp256k1::Signature::parse_slice(&signature.0[..64])?;
+    let recovery_id = RecoveryId::parse(signature.0[64])?;
 
-    let blob_to_sign = Message::parse_slice(&message_digest)?;
+    let blob_to_sign = Message::parse(digest);
 
     let public_key = recover(&blob_to_sign, &signature_rs, &recovery_id)?;
-    let mut from = Address::new_secp256k1(&public_key.serialize().to_vec())?;
-    from.set_network(network);
-
-    let tx_from = match tx {
-        MessageTxAPI::UnsignedMessageAPI(tx) => tx.from,
-        MessageTxAPI::SignedMessageAPI(tx) => tx.message.from,
-    };
-    let expected_from = from.to_string();
+    let signer = Address::new_secp256k1(&public_key.serialize())?;
 
-    // Compare recovered public key with the public key from the transaction
-    if tx_from != expected_from {
+    // Compare recovered public key with the sender, the network of the addresses doesn't matter
+    if signer.to_bytes() != from.to_bytes() {
         return Ok(false);
     }
 
     Ok(verify(&blob_to_sign, &signature_rs, &public_key))
 }
 
-fn verify_bls_signature(
+fn verify_bls_message(
     signature: &SignatureBLS,
-    cbor_buffer: &CborBuffer,
+    unsigned_message: &UnsignedMessage,
 ) -> Result<bool, SignerError> {
-    // TODO: need a function to extract from public key from cbor buffer directly
-    let message = transaction_parse(cbor_buffer, true)?;
-    let message = message.get_message();
+    let pk = bls_signatures::PublicKey::from_bytes(&unsigned_message.from().payload_bytes())?;
 
-    let address = Address::from_str(&message.from)?;
+    let sig = bls_signatures::Signature::from_bytes(signature.as_ref())?;
 
-    let pk = bls_signatures::PublicKey::from_bytes(&address.payload_bytes())?;
+    let result = pk.verify(sig, unsigned_message.to_signing_bytes());
 
-    let sig = bls_signatures::Signature::from_bytes(signature.as_ref())?;
+    Ok(result)
+}
 
-    let message = UnsignedMessage::try_from(&message)?;
-    let signing_bytes = message.to_signing_bytes();
+fn verify_secp256k1_signature(
+    signature: &SignatureSECP256K1,
+    cbor_buffer: &CborBuffer,
+) -> Result<bool, SignerError> {
+    let unsigned_message = cbor_unsigned_message(cbor_buffer)?;
 
-    let result = pk.verify(sig, signing_bytes);
+    let message_digest = utils::get_digest(cbor_buffer.as_ref())?;
 
-    Ok(result)
+    verify_secp256k1_digest(signature, &message_digest, unsigned_message.from())
+}
+
+fn verify_bls_signature(
+    signature: &SignatureBLS,
+    cbor_buffer: &CborBuffer,
+) -> Result<bool, SignerError> {
+    let unsigned_message = cbor_unsigned_message(cbor_buffer)?;
+
+    verify_bls_message(signature, &unsigned_message)
 }
 
 /// Verify a signature. Return a boolean.
 ///
+/// The signer is compared to the `from` address of the message whatever its network.
+///
 /// # Arguments
 ///
 /// * `signature` - RSV format signature or BLS signature
@@ -474,15 +1298,118 @@ pub fn verify_signature(
     Ok(result)
 }
 
+/// Verify a signature like `verify_signature` but reject malleable secp256k1 signatures: `s` in
+/// the upper half of the curve order, `r` or `s` out of range or an invalid recovery ID. Return a
+/// boolean.
+///
+/// # Arguments
+///
+/// * `signature` - RSV format signature or BLS signature
+/// * `cbor_buffer` - The CBOR transaction to verify the signature against
+///
+pub fn verify_signature_strict(
+    signature: &Signature,
+    cbor_buffer: &CborBuffer,
+) -> Result<bool, SignerError> {
+    if let Signature::SignatureSECP256K1(sig_secp256k1) = signature {
+        if !sig_secp256k1.is_canonical() {
+            return Ok(false);
+        }
+    }
+
+    verify_signature(signature, cbor_buffer)
+}
+
+/// Convert a DER encoded secp256k1 signature of a transaction (e.g from an HSM) to the RSV format.
+/// The signature is normalized to a low `s` and gets the recovery ID of the `from` address.
+///
+/// # Arguments
+///
+/// * `der` - The DER encoded signature
+/// * `cbor_buffer` - The signed CBOR transaction
+///
+pub fn transaction_signature_from_der(
+    der: &[u8],
+    cbor_buffer: &CborBuffer,
+) -> Result<SignatureSECP256K1, SignerError> {
+    let unsigned_message = cbor_unsigned_message(cbor_buffer)?;
+    let message_digest = utils::get_digest(cbor_buffer.as_ref())?;
+
+    let compact = SignatureSECP256K1::from_der(der, 0)?
+        .normalize_s()?
+        .to_compact();
+
+    for recovery_id in 0..4 {
+        let signature = SignatureSECP256K1::from_compact(&compact, recovery_id)?;
+
+        let valid = verify_secp256k1_digest(&signature, &message_digest, unsigned_message.from());
+        if let Ok(true) = valid {
+            return Ok(signature);
+        }
+    }
+
+    Err(SignerError::GenericString(
+        "The signature doesn't match the sender of the transaction".to_string(),
+    ))
+}
+
+/// Verify many signatures in parallel. Return one result per signature, in the same order.
+///
+/// # Arguments
+///
+/// * `signatures` - pairs of a signature (RSV format or BLS) and the CBOR transaction it signs
+///
+pub fn verify_signature_batch(
+    signatures: &[(Signature, CborBuffer)],
+) -> Vec<Result<bool, SignerError>> {
+    signatures
+        .par_iter()
+        .map(|(signature, cbor_buffer)| verify_signature(signature, cbor_buffer))
+        .collect()
+}
+
+/// Verify the signature embedded in a signed message. Return a boolean.
+///
+/// The message must be signed by its `from` address (mainnet or testnet), with the scheme of its
+/// signature type.
+///
+/// # Arguments
+///
+/// * `signed_message` - a signed filecoin message
+///
+pub fn verify_signed_message(signed_message: &SignedMessageAPI) -> Result<bool, SignerError> {
+    let signature = Signature::try_from(signed_message.signature.data.clone())?;
+    let unsigned_message = UnsignedMessage::try_from(&signed_message.message)?;
+
+    let result = match &signature {
+        Signature::SignatureSECP256K1(sig_secp256k1) => {
+            if signed_message.signature.sig_type != SigTypes::SigTypeSecp256k1 as u8 {
+                return Ok(false);
+            }
+
+            let message_cbor = transaction_serialize(&signed_message.message)?;
+            let message_digest = utils::get_digest(message_cbor.as_ref())?;
+
+            verify_secp256k1_digest(sig_secp256k1, &message_digest, unsigned_message.from())?
+        }
+        Signature::SignatureBLS(sig_bls) => {
+            if signed_message.signature.sig_type != SigTypes::SigTypeBLS as u8 {
+                return Ok(false);
+            }
+
+            verify_bls_message(sig_bls, &unsigned_message)?
+        }
+    };
+
+    Ok(result)
+}
+
 fn extract_from_pub_key_from_message(
     cbor_message: &CborBuffer,
 ) -> Result<bls_signatures::PublicKey, SignerError> {
-    let message = transaction_parse(cbor_message, true)?;
-
-    let unsigned_message_api = message.get_message();
-    let from_address = Address::from_str(&unsigned_message_api.from)?;
+    let unsigned_message = cbor_unsigned_message(cbor_message)?;
 
-    let pk = bls_signatures::PublicKey::from_bytes(&from_address.payload_bytes())?;
+    let pk = bls_signatures::PublicKey::from_bytes(&unsigned_message.from().payload_bytes())?;
 
     Ok(pk)
 }
@@ -490,10 +1417,7 @@ fn extract_from_pub_key_from_message(
 fn extract_bls_signing_bytes_from_message(
     cbor_message: &CborBuffer,
 ) -> Result<Vec<u8>, SignerError> {
-    let message = transaction_parse(cbor_message, true)?;
-
-    let unsigned_message_api = message.get_message();
-    let unsigned_message = UnsignedMessage::try_from(&unsigned_message_api)?;
+    let unsigned_message = cbor_unsigned_message(cbor_message)?;
 
     Ok(unsigned_message.to_signing_bytes())
 }
@@ -542,13 +1466,81 @@ pub fn verify_aggregated_signature(
     Ok(bls_signatures::verify(&sig, &hashes, pks.as_slice()))
 }
 
+/// Aggregate BLS signatures into a single BLS signature
+///
+/// # Arguments
+///
+/// * `signatures` - The BLS signatures to aggregate (at least one)
+///
+pub fn aggregate_bls_signatures(signatures: &[SignatureBLS]) -> Result<SignatureBLS, SignerError> {
+    if signatures.is_empty() {
+        return Err(SignerError::GenericString(
+            "No signature to aggregate".to_string(),
+        ));
+    }
+
+    let sigs = signatures
+        .iter()
+        .map(|signature| bls_signatures::Signature::from_bytes(signature.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let aggregated_signature = bls_signatures::aggregate(&sigs)?;
+
+    SignatureBLS::try_from(aggregated_signature.as_bytes())
+}
+
+/// Aggregate the signatures of BLS signed messages. Return the aggregated signature and the CIDs
+/// of the unsigned messages (BLS messages are included in a block without their signature).
+///
+/// # Arguments
+///
+/// * `signed_messages` - The BLS signed messages (at least one)
+///
+pub fn aggregate_bls_messages(
+    signed_messages: &[SignedMessageAPI],
+) -> Result<BLSAggregateAPI, SignerError> {
+    use forest_encoding::Cbor;
+
+    let mut signatures = Vec::with_capacity(signed_messages.len());
+    let mut cids = Vec::with_capacity(signed_messages.len());
+
+    for signed_message in signed_messages {
+        if signed_message.signature.sig_type != SigTypes::SigTypeBLS as u8 {
+            return Err(SignerError::GenericString(format!(
+                "Message from {} is not BLS signed",
+                signed_message.message.from
+            )));
+        }
+
+        signatures.push(SignatureBLS::try_from(
+            signed_message.signature.data.clone(),
+        )?);
+
+        let unsigned_message = UnsignedMessage::try_from(&signed_message.message)?;
+        cids.push(unsigned_message.cid()?.to_string());
+    }
+
+    let signature = aggregate_bls_signatures(&signatures)?;
+
+    Ok(BLSAggregateAPI {
+        signature: SignatureAPI::from(&Signature::SignatureBLS(signature)),
+        cids,
+    })
+}
+
+/// Amount in attoFIL as expected by the message fields, `amount` may be in any FIL unit
+/// (e.g "1.5 FIL", "200 nanoFIL" or "12" attoFIL)
+fn atto_string(amount: &str) -> Result<String, SignerError> {
+    Ok(FilAmount::from_str(amount)?.to_atto_string())
+}
+
 /// Utilitary function to create a create multisig message. Return an unsigned message.
 ///
 /// # Arguments
 ///
 /// * `sender_address` - A string address
 /// * `addresses` - List of string addresses of the multisig
-/// * `value` - Value to send on the multisig
+/// * `value` - Value to send on the multisig, attoFIL or with a unit (e.g "1.5 FIL")
 /// * `required` - Number of required signatures required
 /// * `nonce` - Nonce of the message
 /// * `duration` - Duration of the multisig
@@ -610,10 +1602,10 @@ pub fn create_multisig(
         to: INIT_ACTOR_ADDR.to_string(),
         from: sender_address,
         nonce,
-        value,
+        value: atto_string(&value)?,
         gas_limit,
-        gas_fee_cap,
-        gas_premium,
+        gas_fee_cap: atto_string(&gas_fee_cap)?,
+        gas_premium: atto_string(&gas_premium)?,
         method: MethodInit::Exec as u64,
         params: base64::encode(serialized_params.bytes()),
     };
@@ -628,7 +1620,7 @@ pub fn create_multisig(
 /// * `multisig_address` - A string address
 /// * `to_address` - A string address
 /// * `from_address` - A string address
-/// * `amount` - Amount of the transaction
+/// * `amount` - Amount of the transaction, attoFIL or with a unit (e.g "1.5 FIL")
 /// * `nonce` - Nonce of the message
 ///
 #[allow(clippy::too_many_arguments)]
@@ -644,7 +1636,7 @@ pub fn proposal_multisig_message(
 ) -> Result<UnsignedMessageAPI, SignerError> {
     let propose_params_multisig = multisig::ProposeParams {
         to: Address::from_str(&to_address)?,
-        value: BigInt::from_str(&amount)?,
+        value: FilAmount::from_str(&amount)?.into_atto(),
         method: 0,
         params: forest_vm::Serialized::new(Vec::new()),
     };
@@ -659,8 +1651,8 @@ pub fn proposal_multisig_message(
         nonce,
         value: "0".to_string(),
         gas_limit,
-        gas_fee_cap,
-        gas_premium,
+        gas_fee_cap: atto_string(&gas_fee_cap)?,
+        gas_premium: atto_string(&gas_premium)?,
         method: multisig::MethodMultisig::Propose as u64,
         params: base64::encode(params.bytes()),
     };
@@ -685,7 +1677,7 @@ fn approve_or_cancel_multisig_message(
     let proposal_parameter = multisig::ProposalHashData {
         requester: Address::from_str(&proposer_address)?,
         to: Address::from_str(&to_address)?,
-        value: BigInt::from_str(&amount)?,
+        value: FilAmount::from_str(&amount)?.into_atto(),
         method: 0,
         params: forest_vm::Serialized::new(Vec::new()),
     };
@@ -709,8 +1701,8 @@ fn approve_or_cancel_multisig_message(
         nonce,
         value: "0".to_string(),
         gas_limit,
-        gas_fee_cap,
-        gas_premium,
+        gas_fee_cap: atto_string(&gas_fee_cap)?,
+        gas_premium: atto_string(&gas_premium)?,
         method,
         params: base64::encode(params.bytes()),
     };
@@ -726,7 +1718,7 @@ fn approve_or_cancel_multisig_message(
 /// * `message_id` - message id
 /// * `proposer_address` - A string address
 /// * `to_address` - A string address
-/// * `amount` - Amount of the transaction
+/// * `amount` - Amount of the transaction, attoFIL or with a unit (e.g "1.5 FIL")
 /// * `from_address` - A string address
 /// * `nonce` - Nonce of the message
 ///
@@ -766,7 +1758,7 @@ pub fn approve_multisig_message(
 /// * `message_id` - message id
 /// * `proposer_address` - A string address
 /// * `to_address` - A string address
-/// * `amount` - Amount of the transaction
+/// * `amount` - Amount of the transaction, attoFIL or with a unit (e.g "1.5 FIL")
 /// * `from_address` - A string address
 /// * `nonce` - Nonce of the message
 ///
@@ -816,7 +1808,7 @@ pub fn serialize_params(params: MessageParams) -> Result<CborBuffer, SignerError
 ///
 /// * `from_address` - A string address
 /// * `to_address` - A string address
-/// * `value` - Amount to put in the payment channel initially
+/// * `value` - Amount to put in the payment channel initially, attoFIL or with a unit
 /// * `nonce` - Nonce of the message; should be from_address's MpoolGetNonce() value
 ///
 pub fn create_pymtchan(
@@ -853,10 +1845,10 @@ pub fn create_pymtchan(
         to: init_actor_address.to_string(),
         from: from_address,
         nonce,
-        value,
+        value: atto_string(&value)?,
         gas_limit,
-        gas_fee_cap,
-        gas_premium,
+        gas_fee_cap: atto_string(&gas_fee_cap)?,
+        gas_premium: atto_string(&gas_premium)?,
         method: MethodInit::Exec as u64,
         params: base64::encode(serialized_params.bytes()),
     };
@@ -900,8 +1892,8 @@ pub fn update_pymtchan(
         nonce,
         value: "0".to_string(),
         gas_limit,
-        gas_fee_cap,
-        gas_premium,
+        gas_fee_cap: atto_string(&gas_fee_cap)?,
+        gas_premium: atto_string(&gas_premium)?,
         method: paych::MethodsPaych::UpdateChannelState as u64,
         params: base64::encode(serialized_params.bytes()),
     };
@@ -932,8 +1924,8 @@ pub fn settle_pymtchan(
         nonce,
         value: "0".to_string(),
         gas_limit,
-        gas_fee_cap,
-        gas_premium,
+        gas_fee_cap: atto_string(&gas_fee_cap)?,
+        gas_premium: atto_string(&gas_premium)?,
         method: paych::MethodsPaych::Settle as u64,
         params: base64::encode(Vec::new()),
     };
@@ -964,8 +1956,8 @@ pub fn collect_pymtchan(
         nonce,
         value: "0".to_string(),
         gas_limit,
-        gas_fee_cap,
-        gas_premium,
+        gas_fee_cap: atto_string(&gas_fee_cap)?,
+        gas_premium: atto_string(&gas_premium)?,
         method: paych::MethodsPaych::Collect as u64,
         params: base64::encode(Vec::new()),
     };
@@ -973,6 +1965,129 @@ pub fn collect_pymtchan(
     Ok(pch_collect_message_api)
 }
 
+/// Verify a signature of arbitrary bytes, like Lotus `WalletVerify`. Return a boolean.
+///
+/// # Arguments
+///
+/// * `signature` - RSV format signature or BLS signature
+/// * `address` - the secp256k1 or BLS address expected to have signed
+/// * `bytes` - the signed bytes
+/// * `prefixed` - the bytes were signed behind the `utils::SIGNED_MESSAGE_PREFIX` domain separation prefix
+///
+pub fn verify_bytes(
+    signature: &Signature,
+    address: &str,
+    bytes: &[u8],
+    prefixed: bool,
+) -> Result<bool, SignerError> {
+    let address = Address::from_str(address)?;
+
+    let prefixed_bytes;
+    let bytes = if prefixed {
+        prefixed_bytes = utils::prefix_signed_message(bytes);
+        &prefixed_bytes[..]
+    } else {
+        bytes
+    };
+
+    match (signature, address.protocol()) {
+        (Signature::SignatureSECP256K1(signature), Protocol::Secp256k1) => {
+            let signature_rs = secp256k1::Signature::parse_slice(&signature.0[..64])?;
+            let recovery_id = RecoveryId::parse(signature.0[64])?;
+            let message = Message::parse(&blake2b_256(bytes));
+
+            let public_key = match recover(&message, &signature_rs, &recovery_id) {
+                Ok(public_key) => public_key,
+                Err(_) => return Ok(false),
+            };
+            let signer = Address::new_secp256k1(&public_key.serialize())?;
+
+            Ok(signer.payload_bytes() == address.payload_bytes()
+                && verify(&message, &signature_rs, &public_key))
+        }
+        (Signature::SignatureBLS(signature), Protocol::BLS) => {
+            let pk = bls_signatures::PublicKey::from_bytes(&address.payload_bytes())?;
+            let sig = bls_signatures::Signature::from_bytes(signature.as_ref())?;
+
+            Ok(pk.verify(sig, bytes))
+        }
+        _ => Err(SignerError::GenericString(
+            "Signature type does not match the address protocol".to_string(),
+        )),
+    }
+}
+
+fn recover_secp256k1_signer(
+    signature: &SignatureSECP256K1,
+    digest: &[u8; 32],
+    testnet: bool,
+) -> Result<WatchOnlyKey, SignerError> {
+    let signature_rs = secp256k1::Signature::parse_slice(&signature.0[..64])?;
+    let recovery_id = RecoveryId::parse(signature.0[64])?;
+
+    let public_key = recover(&Message::parse(digest), &signature_rs, &recovery_id)?;
+
+    let mut address = Address::new_secp256k1(&public_key.serialize())?;
+    if testnet {
+        address.set_network(Network::Testnet);
+    } else {
+        address.set_network(Network::Mainnet);
+    }
+
+    Ok(WatchOnlyKey {
+        public_key: PublicKey::PublicKeySECP256K1(PublicKeySECP256K1(public_key.serialize())),
+        address: address.to_string(),
+    })
+}
+
+fn secp256k1_signature(signature: &Signature) -> Result<&SignatureSECP256K1, SignerError> {
+    match signature {
+        Signature::SignatureSECP256K1(signature) => Ok(signature),
+        Signature::SignatureBLS(_) => Err(SignerError::GenericString(
+            "The signer of a BLS signature cannot be recovered".to_string(),
+        )),
+    }
+}
+
+/// Recover the public key and address that produced a secp256k1 message signature
+///
+/// The signature is not checked against any expected `from` address.
+///
+/// # Arguments
+///
+/// * `signature` - RSV format signature
+/// * `cbor_buffer` - the CBOR transaction that was signed
+/// * `testnet` - network of the returned address, `true` if testnet else `false` for mainnet
+///
+pub fn recover_signer(
+    signature: &Signature,
+    cbor_buffer: &CborBuffer,
+    testnet: bool,
+) -> Result<WatchOnlyKey, SignerError> {
+    let message_digest = utils::get_digest(cbor_buffer.as_ref())?;
+
+    recover_secp256k1_signer(secp256k1_signature(signature)?, &message_digest, testnet)
+}
+
+/// Recover the public key and address that signed a secp256k1 signed message
+///
+/// The returned address is on the network of the message `from` address, compare both to check
+/// the message was signed by its sender.
+///
+/// # Arguments
+///
+/// * `signed_message` - a signed filecoin message
+///
+pub fn recover_signed_message_signer(
+    signed_message: &SignedMessageAPI,
+) -> Result<WatchOnlyKey, SignerError> {
+    let signature = Signature::try_from(signed_message.signature.data.clone())?;
+    let cbor_buffer = transaction_serialize(&signed_message.message)?;
+    let testnet = signed_message.message.from.starts_with('t');
+
+    recover_signer(&signature, &cbor_buffer, testnet)
+}
+
 /// Sign a voucher for payment channel
 ///
 /// # Arguments
@@ -983,26 +2098,42 @@ pub fn collect_pymtchan(
 pub fn sign_voucher(
     voucher_string: String,
     private_key: &PrivateKey,
+) -> Result<String, SignerError> {
+    sign_voucher_with_signer(voucher_string, &PrivateKeySigner::Secp256k1(private_key))
+}
+
+/// Sign a voucher for payment channel with a `Signer`
+///
+/// # Arguments
+///
+/// * `voucher_string` - Voucher as base64 string;
+/// * `signer` - A secp256k1 or BLS `Signer`;
+///
+pub fn sign_voucher_with_signer<S: Signer + ?Sized>(
+    voucher_string: String,
+    signer: &S,
 ) -> Result<String, SignerError> {
     let decoded_voucher = base64::decode(voucher_string)?;
     let mut voucher: paych::SignedVoucher = from_slice(&decoded_voucher)?;
 
-    let secret_key = secp256k1::SecretKey::parse_slice(&private_key.0)?;
-
     let svb = voucher.signing_bytes()?;
     let digest = utils::get_digest_voucher(&svb)?;
 
-    let blob_to_sign = Message::parse_slice(&digest)?;
-
-    let (signature_rs, recovery_id) = sign(&blob_to_sign, &secret_key);
-
-    let mut signature = SignatureSECP256K1 { 0: [0; 65] };
-    signature.0[..64].copy_from_slice(&signature_rs.serialize()[..]);
-    signature.0[64] = recovery_id.serialize();
+    let signature = match signer.address()?.protocol() {
+        Protocol::Secp256k1 => forest_crypto::signature::Signature::new_secp256k1(
+            signer.sign_digest(&digest)?.0.to_vec(),
+        ),
+        Protocol::BLS => {
+            forest_crypto::signature::Signature::new_bls(signer.sign_bytes(&digest)?.0.to_vec())
+        }
+        _ => {
+            return Err(SignerError::GenericString(
+                "Signer address should be BLS or Secp256k1".to_string(),
+            ));
+        }
+    };
 
-    voucher.signature = Some(forest_crypto::signature::Signature::new_secp256k1(
-        signature.0.to_vec(),
-    ));
+    voucher.signature = Some(signature);
 
     let binary_voucher = to_vec(&voucher)?;
     let cbor_voucher = base64::encode(binary_voucher);
@@ -1017,7 +2148,7 @@ pub fn sign_voucher(
 /// * `payment_channel_address` - The payment channel address;
 /// * `time_lock_min` - Time lock min;
 /// * `time_lock_maax` - Time lock max;
-/// * `amount` - Amount in the voucher;
+/// * `amount` - Amount in the voucher, attoFIL or with a unit (e.g "1.5 FIL");
 /// * `lane` - Lane of the voucher;
 /// * `nonce` - Next nonce of the voucher;
 ///
@@ -1031,14 +2162,7 @@ pub fn create_voucher(
     min_settle_height: i64,
 ) -> Result<String, SignerError> {
     let pch = Address::from_str(&payment_channel_address)?;
-    let amount = match BigInt::parse_bytes(amount.as_bytes(), 10) {
-        Some(value) => value,
-        None => {
-            return Err(SignerError::GenericString(
-                "`amount` couldn't be parsed.".to_string(),
-            ));
-        }
-    };
+    let amount = FilAmount::from_str(&amount)?.into_atto();
 
     let voucher = paych::SignedVoucher {
         channel_addr: pch,
@@ -1253,6 +2377,32 @@ pub fn verify_voucher_signature(
     }
 }
 
+/// Recover the public key and address that signed a voucher with secp256k1
+///
+/// # Arguments
+///
+/// * `voucher_base64_string` - The signed voucher as a base64 string;
+/// * `testnet` - network of the returned address, `true` if testnet else `false` for mainnet;
+///
+pub fn recover_voucher_signer(
+    voucher_base64_string: String,
+    testnet: bool,
+) -> Result<WatchOnlyKey, SignerError> {
+    let decoded_voucher = base64::decode(voucher_base64_string)?;
+    let signed_voucher: paych::SignedVoucher = from_slice(&decoded_voucher)?;
+
+    let signature = signed_voucher
+        .signature
+        .as_ref()
+        .ok_or_else(|| SignerError::GenericString("Voucher not signed.".to_string()))?;
+    let signature = Signature::try_from(signature.bytes().to_vec())?;
+
+    let sv_bytes = signed_voucher.signing_bytes()?;
+    let digest = utils::get_digest_voucher(&sv_bytes)?;
+
+    recover_secp256k1_signer(secp256k1_signature(&signature)?, &digest, testnet)
+}
+
 /// Return the CID of a message
 ///
 /// # Arguments
@@ -1276,3 +2426,59 @@ pub fn get_cid(message_api: MessageTxAPI) -> Result<String, SignerError> {
         }
     }
 }
+
+/// Returns the robust (f2) address of the actor an Init actor `Exec` message will create
+///
+/// The address only depends on the sender and the nonce of the message: it is the actor address
+/// of the CBOR encoded sender address followed by the big endian nonce and the number of actors
+/// the message created before (always 0 for `Exec`).
+///
+/// # Arguments
+///
+/// * `unsigned_message` - an unsigned message sent to `INIT_ACTOR_ADDR` calling `Exec` (e.g from `create_multisig` or `create_pymtchan`)
+///
+pub fn predict_robust_address(
+    unsigned_message: &UnsignedMessageAPI,
+) -> Result<String, SignerError> {
+    let to = Address::from_str(&unsigned_message.to)?;
+
+    if to.payload() != INIT_ACTOR_ADDR.payload()
+        || unsigned_message.method != MethodInit::Exec as u64
+    {
+        return Err(SignerError::GenericString(
+            "Only messages calling `Exec` on the Init actor create an actor".to_string(),
+        ));
+    }
+
+    // The sender is resolved on chain to its key (or delegated) address, which cannot be done
+    // offline for an ID address
+    let from_bytes = match unsigned_message.from.as_bytes().get(1) {
+        Some(b'1') | Some(b'3') => Address::from_str(&unsigned_message.from)?.to_bytes(),
+        Some(b'4') => {
+            let from = DelegatedAddress::from_str(&unsigned_message.from)?;
+
+            let mut from_bytes = vec![delegated_address::DELEGATED_PROTOCOL];
+            from_bytes.extend_from_slice(&from.payload());
+            from_bytes
+        }
+        _ => {
+            return Err(SignerError::GenericString(
+                "The sender must be a secp256k1, BLS or delegated address".to_string(),
+            ));
+        }
+    };
+
+    let mut data = serde_cbor::to_vec(&serde_bytes::Bytes::new(&from_bytes))?;
+    data.extend_from_slice(&unsigned_message.nonce.to_be_bytes());
+    data.extend_from_slice(&0u64.to_be_bytes());
+
+    let mut address = Address::new_actor(&data);
+
+    if unsigned_message.from.starts_with('t') {
+        address.set_network(Network::Testnet);
+    } else {
+        address.set_network(Network::Mainnet);
+    }
+
+    Ok(address.to_string())
+}
@@ -0,0 +1,396 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use data_encoding::BASE32_NOPAD;
+
+use crate::api::{LotusKeyInfo, LotusKeyType, SignedMessageAPI, UnsignedMessageAPI};
+use crate::error::SignerError;
+use crate::{key_recover_lotus_key_info, transaction_sign, ExtendedKey};
+
+/// Prefix of the keystore entries holding wallet keys
+const WALLET_KEY_PREFIX: &str = "wallet-";
+/// Keystore entry holding a copy of the default wallet key
+const DEFAULT_KEY_NAME: &str = "default";
+
+/// Lotus repository keystore (e.g `~/.lotus/keystore`)
+///
+/// Every entry is a file named after the base32 (RFC 4648, no padding) encoding of the key name
+/// and containing a KeyInfo JSON document. Wallet keys are named `wallet-<address>`.
+pub struct LotusKeystore {
+    path: PathBuf,
+}
+
+impl LotusKeystore {
+    /// Open (and create if needed) a Lotus keystore directory
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, SignerError> {
+        fs::create_dir_all(&path)?;
+        set_permissions(path.as_ref(), 0o700)?;
+
+        Ok(LotusKeystore {
+            path: path.as_ref().to_path_buf(),
+        })
+    }
+
+    /// List the wallet addresses of the keystore (sorted)
+    pub fn list(&self) -> Result<Vec<String>, SignerError> {
+        let mut addresses = Vec::new();
+
+        for name in self.key_names()? {
+            if let Some(address) = name.strip_prefix(WALLET_KEY_PREFIX) {
+                addresses.push(address.to_string());
+            }
+        }
+
+        addresses.sort();
+
+        Ok(addresses)
+    }
+
+    /// Load the key of a wallet address
+    ///
+    /// The lookup ignores the network prefix, the returned address uses the network of `address`.
+    pub fn get(&self, address: &str) -> Result<ExtendedKey, SignerError> {
+        let key_info = self.read_key_info(&self.wallet_key_name(address)?)?;
+
+        key_recover_lotus_key_info(&key_info, is_testnet_address(address))
+    }
+
+    /// Write a new wallet key, an existing entry is never overwritten
+    pub fn put(&self, extended_key: &ExtendedKey) -> Result<(), SignerError> {
+        if self.find_wallet_key_name(&extended_key.address)?.is_some() {
+            return Err(SignerError::GenericString(format!(
+                "Key `{}` already exists",
+                extended_key.address
+            )));
+        }
+
+        let name = format!("{}{}", WALLET_KEY_PREFIX, extended_key.address);
+
+        self.write_key_info(&name, &LotusKeyInfo::from(extended_key))
+    }
+
+    /// Load the default wallet key, if any
+    ///
+    /// # Arguments
+    ///
+    /// * `testnet` - specify the network of the returned address
+    ///
+    pub fn default_key(&self, testnet: bool) -> Result<Option<ExtendedKey>, SignerError> {
+        if !self.entry_path(DEFAULT_KEY_NAME).exists() {
+            return Ok(None);
+        }
+
+        let key_info = self.read_key_info(DEFAULT_KEY_NAME)?;
+
+        Ok(Some(key_recover_lotus_key_info(&key_info, testnet)?))
+    }
+
+    /// Make a wallet address the default one
+    pub fn set_default(&self, address: &str) -> Result<(), SignerError> {
+        let key_info = self.read_key_info(&self.wallet_key_name(address)?)?;
+
+        self.write_key_info(DEFAULT_KEY_NAME, &key_info)
+    }
+
+    /// Sign a transaction with the key of its `from` address
+    pub fn transaction_sign(
+        &self,
+        unsigned_message: &UnsignedMessageAPI,
+    ) -> Result<SignedMessageAPI, SignerError> {
+        let key_name = self.wallet_key_name(&unsigned_message.from)?;
+        let key_info = self.read_key_info(&key_name)?;
+
+        let expected_protocol = match key_info.key_type {
+            LotusKeyType::Secp256k1 => b'1',
+            LotusKeyType::BLS => b'3',
+        };
+
+        if unsigned_message.from.as_bytes().get(1) != Some(&expected_protocol) {
+            return Err(SignerError::GenericString(
+                "Key type does not match the `from` address".to_string(),
+            ));
+        }
+
+        let extended_key =
+            key_recover_lotus_key_info(&key_info, is_testnet_address(&unsigned_message.from))?;
+
+        transaction_sign(unsigned_message, &extended_key.private_key)
+    }
+
+    fn key_names(&self) -> Result<Vec<String>, SignerError> {
+        let mut names = Vec::new();
+
+        for entry in fs::read_dir(&self.path)? {
+            let file_name = entry?.file_name();
+
+            // Ignore entries that are not keys (e.g temporary files)
+            let name = file_name
+                .to_str()
+                .and_then(|file_name| BASE32_NOPAD.decode(file_name.as_bytes()).ok())
+                .and_then(|name| String::from_utf8(name).ok());
+
+            if let Some(name) = name {
+                names.push(name);
+            }
+        }
+
+        Ok(names)
+    }
+
+    fn find_wallet_key_name(&self, address: &str) -> Result<Option<String>, SignerError> {
+        let payload = address.get(1..).unwrap_or_default();
+
+        for name in self.key_names()? {
+            let stored_payload = name
+                .strip_prefix(WALLET_KEY_PREFIX)
+                .and_then(|stored_address| stored_address.get(1..));
+
+            if !payload.is_empty() && stored_payload == Some(payload) {
+                return Ok(Some(name));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn wallet_key_name(&self, address: &str) -> Result<String, SignerError> {
+        self.find_wallet_key_name(address)?.ok_or_else(|| {
+            SignerError::GenericString(format!("Key `{}` not found in keystore", address))
+        })
+    }
+
+    fn entry_path(&self, name: &str) -> PathBuf {
+        self.path.join(BASE32_NOPAD.encode(name.as_bytes()))
+    }
+
+    fn read_key_info(&self, name: &str) -> Result<LotusKeyInfo, SignerError> {
+        let content = fs::read(self.entry_path(name))?;
+
+        serde_json::from_slice(&content).map_err(|err| SignerError::GenericString(err.to_string()))
+    }
+
+    fn write_key_info(&self, name: &str, key_info: &LotusKeyInfo) -> Result<(), SignerError> {
+        let entry_path = self.entry_path(name);
+        let tmp_path = self.path.join(format!(".{}.tmp", name));
+
+        let content = serde_json::to_vec(key_info)
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+        // Left over by an interrupted write
+        if let Err(err) = fs::remove_file(&tmp_path) {
+            if err.kind() != io::ErrorKind::NotFound {
+                return Err(err.into());
+            }
+        }
+
+        let mut file = create_private_file(&tmp_path)?;
+        let written = file
+            .write_all(&content)
+            .and_then(|_| file.sync_all())
+            .and_then(|_| fs::rename(&tmp_path, &entry_path));
+
+        if let Err(err) = written {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+}
+
+fn is_testnet_address(address: &str) -> bool {
+    address.starts_with('t')
+}
+
+// Lotus refuses to read a keystore with permissions more relaxed than these
+#[cfg(unix)]
+fn set_permissions(path: &Path, mode: u32) -> Result<(), SignerError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::set_permissions(path, fs::Permissions::from_mode(mode))?;
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn set_permissions(_path: &Path, _mode: u32) -> Result<(), SignerError> {
+    Ok(())
+}
+
+// The file is private from its creation, a key is never readable by other users
+#[cfg(unix)]
+fn create_private_file(path: &Path) -> Result<fs::File, SignerError> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    Ok(fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)?)
+}
+
+#[cfg(not(unix))]
+fn create_private_file(path: &Path) -> Result<fs::File, SignerError> {
+    Ok(fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::UnsignedMessageAPI;
+    use crate::lotus_keystore::LotusKeystore;
+    use crate::signature::Signature;
+    use crate::{
+        key_recover, key_recover_bls, transaction_serialize, verify_signature, PrivateKey,
+    };
+    use data_encoding::BASE32_NOPAD;
+    use std::convert::TryFrom;
+
+    const PRIVATE_KEY: &str = "8VcW07ADswS4BV2cxi5rnIadVsyTDDhY1NfDH19T8Uo=";
+    const BLS_PRIVATE_KEY: &str = "P2pSgkvsZSgi0LOczuHmSXT1+l/hvSs3fVBb4y8OgVo=";
+
+    #[test]
+    fn entry_names() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = LotusKeystore::open(dir.path()).unwrap();
+
+        assert_eq!(
+            keystore.entry_path("default"),
+            dir.path().join("MRSWMYLVNR2A")
+        );
+        assert_eq!(
+            BASE32_NOPAD.decode(b"O5QWY3DFOQWWMMI").unwrap(),
+            b"wallet-f1".to_vec()
+        );
+    }
+
+    #[test]
+    fn put_list_get() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = LotusKeystore::open(dir.path()).unwrap();
+
+        let secp_key = key_recover(
+            &PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap(),
+            false,
+        )
+        .unwrap();
+        let bls_key = key_recover_bls(
+            &PrivateKey::try_from(BLS_PRIVATE_KEY.to_string()).unwrap(),
+            true,
+        )
+        .unwrap();
+
+        keystore.put(&secp_key).unwrap();
+        keystore.put(&bls_key).unwrap();
+        assert!(keystore.put(&secp_key).is_err());
+
+        let mut expected = vec![secp_key.address.clone(), bls_key.address.clone()];
+        expected.sort();
+        assert_eq!(keystore.list().unwrap(), expected);
+
+        let loaded = keystore.get(&secp_key.address).unwrap();
+        assert_eq!(loaded.private_key.0, secp_key.private_key.0);
+        assert_eq!(loaded.address, secp_key.address);
+
+        // Same key on the other network
+        let testnet_address = format!("t{}", &secp_key.address[1..]);
+        let loaded = keystore.get(&testnet_address).unwrap();
+        assert_eq!(loaded.address, testnet_address);
+
+        let loaded = keystore.get(&bls_key.address).unwrap();
+        assert_eq!(loaded.public_key.to_vec(), bls_key.public_key.to_vec());
+
+        assert!(keystore
+            .get("f1rovwtiuo5ncslpmpjftzu5akswbgsgighjazxoi")
+            .is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn private_entries() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = LotusKeystore::open(dir.path()).unwrap();
+
+        let secp_key = key_recover(
+            &PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap(),
+            false,
+        )
+        .unwrap();
+        let name = format!("wallet-{}", secp_key.address);
+
+        // A temporary file left over by an interrupted write doesn't block the next one
+        std::fs::write(dir.path().join(format!(".{}.tmp", name)), b"partial").unwrap();
+        keystore.put(&secp_key).unwrap();
+
+        let mode = std::fs::metadata(keystore.entry_path(&name))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        let entries: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[test]
+    fn default_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = LotusKeystore::open(dir.path()).unwrap();
+
+        assert!(keystore.default_key(false).unwrap().is_none());
+
+        let secp_key = key_recover(
+            &PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap(),
+            false,
+        )
+        .unwrap();
+        keystore.put(&secp_key).unwrap();
+        keystore.set_default(&secp_key.address).unwrap();
+
+        let default_key = keystore.default_key(false).unwrap().unwrap();
+        assert_eq!(default_key.address, secp_key.address);
+
+        // The default entry is not a wallet address
+        assert_eq!(keystore.list().unwrap(), vec![secp_key.address]);
+    }
+
+    #[test]
+    fn sign_with_stored_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = LotusKeystore::open(dir.path()).unwrap();
+
+        let secp_key = key_recover(
+            &PrivateKey::try_from(PRIVATE_KEY.to_string()).unwrap(),
+            true,
+        )
+        .unwrap();
+        keystore.put(&secp_key).unwrap();
+
+        let unsigned_message = UnsignedMessageAPI {
+            to: "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy".to_string(),
+            from: secp_key.address.clone(),
+            nonce: 1,
+            value: "100000".to_string(),
+            gas_limit: 25000,
+            gas_fee_cap: "2500".to_string(),
+            gas_premium: "2500".to_string(),
+            method: 0,
+            params: "".to_string(),
+        };
+
+        let signed_message = keystore.transaction_sign(&unsigned_message).unwrap();
+        let cbor = transaction_serialize(&unsigned_message).unwrap();
+
+        let signature = Signature::try_from(signed_message.signature.data).unwrap();
+        assert!(verify_signature(&signature, &cbor).unwrap());
+
+        let mut unknown_message = unsigned_message;
+        unknown_message.from = "t1rovwtiuo5ncslpmpjftzu5akswbgsgighjazxoi".to_string();
+        assert!(keystore.transaction_sign(&unknown_message).is_err());
+    }
+}
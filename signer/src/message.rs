@@ -0,0 +1,748 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use forest_address::{Address, Protocol};
+use forest_encoding::to_vec;
+use forest_message::{Message as _, UnsignedMessage};
+use forest_vm::{MethodNum, Serialized, TokenAmount, METHOD_SEND};
+use num_bigint_chainsafe::BigInt;
+use num_traits::Zero;
+
+use extras::INIT_ACTOR_ADDR;
+
+use crate::api::{MessageIssueAPI, MessageIssueKind, MessageValidationAPI, UnsignedMessageAPI};
+use crate::deserialize_params;
+use crate::error::SignerError;
+
+/// The only message version supported by the network
+pub const MESSAGE_VERSION: i64 = 0;
+
+/// Maximum gas of a block, and so of a message
+pub const BLOCK_GAS_LIMIT: i64 = 10_000_000_000;
+
+/// Total supply of FIL in attoFIL (2 billion FIL)
+pub const FIL_TOTAL_SUPPLY: &str = "2000000000000000000000000000";
+
+/// Total supply of FIL in attoFIL, the value of `FIL_TOTAL_SUPPLY`
+pub fn fil_total_supply() -> TokenAmount {
+    BigInt::from(2_000_000_000u64) * num_traits::pow(BigInt::from(10), 18)
+}
+
+/// Unsigned message with typed fields
+///
+/// A `TypedMessage` is always valid: it is built with a `TypedMessageBuilder` or converted from an
+/// `UnsignedMessageAPI` or a forest `UnsignedMessage`, and all of them validate the fields.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedMessage {
+    version: i64,
+    to: Address,
+    from: Address,
+    nonce: u64,
+    value: TokenAmount,
+    method: MethodNum,
+    params: Serialized,
+    gas_limit: i64,
+    gas_fee_cap: TokenAmount,
+    gas_premium: TokenAmount,
+}
+
+impl TypedMessage {
+    /// Start a message from `from` to `to`, by default a send of 0 FIL without gas
+    pub fn builder(from: Address, to: Address) -> TypedMessageBuilder {
+        TypedMessageBuilder::new(from, to)
+    }
+
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
+    pub fn to(&self) -> &Address {
+        &self.to
+    }
+
+    pub fn from(&self) -> &Address {
+        &self.from
+    }
+
+    pub fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    pub fn value(&self) -> &TokenAmount {
+        &self.value
+    }
+
+    pub fn method(&self) -> MethodNum {
+        self.method
+    }
+
+    pub fn params(&self) -> &Serialized {
+        &self.params
+    }
+
+    pub fn gas_limit(&self) -> i64 {
+        self.gas_limit
+    }
+
+    pub fn gas_fee_cap(&self) -> &TokenAmount {
+        &self.gas_fee_cap
+    }
+
+    pub fn gas_premium(&self) -> &TokenAmount {
+        &self.gas_premium
+    }
+
+    /// Builder initialized with the fields of this message, e.g to bump the nonce or the fees
+    pub fn to_builder(&self) -> TypedMessageBuilder {
+        TypedMessageBuilder {
+            message: self.clone(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), SignerError> {
+        if self.version != MESSAGE_VERSION {
+            return Err(SignerError::GenericString(format!(
+                "Unsupported message version {}",
+                self.version
+            )));
+        }
+
+        if self.value < BigInt::zero() {
+            return Err(SignerError::GenericString(
+                "Value can't be negative".to_string(),
+            ));
+        }
+
+        if self.value > fil_total_supply() {
+            return Err(SignerError::GenericString(
+                "Value can't exceed the total supply of FIL".to_string(),
+            ));
+        }
+
+        if self.gas_limit < 0 || self.gas_limit > BLOCK_GAS_LIMIT {
+            return Err(SignerError::GenericString(format!(
+                "Gas limit must be between 0 and {}",
+                BLOCK_GAS_LIMIT
+            )));
+        }
+
+        if self.gas_fee_cap < BigInt::zero() || self.gas_premium < BigInt::zero() {
+            return Err(SignerError::GenericString(
+                "Gas fee cap and gas premium can't be negative".to_string(),
+            ));
+        }
+
+        if self.gas_premium > self.gas_fee_cap {
+            return Err(SignerError::GenericString(
+                "Gas premium can't be greater than the gas fee cap".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Builder of a `TypedMessage`, the fields are validated by `build`
+#[derive(Debug, Clone)]
+pub struct TypedMessageBuilder {
+    message: TypedMessage,
+}
+
+impl TypedMessageBuilder {
+    /// Start a message from `from` to `to`, by default a send of 0 FIL without gas
+    pub fn new(from: Address, to: Address) -> Self {
+        TypedMessageBuilder {
+            message: TypedMessage {
+                version: MESSAGE_VERSION,
+                to,
+                from,
+                nonce: 0,
+                value: TokenAmount::zero(),
+                method: METHOD_SEND,
+                params: Serialized::new(Vec::new()),
+                gas_limit: 0,
+                gas_fee_cap: TokenAmount::zero(),
+                gas_premium: TokenAmount::zero(),
+            },
+        }
+    }
+
+    pub fn version(mut self, version: i64) -> Self {
+        self.message.version = version;
+        self
+    }
+
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.message.nonce = nonce;
+        self
+    }
+
+    /// Value in attoFIL
+    pub fn value(mut self, value: TokenAmount) -> Self {
+        self.message.value = value;
+        self
+    }
+
+    pub fn method(mut self, method: MethodNum) -> Self {
+        self.message.method = method;
+        self
+    }
+
+    /// Already serialized parameters
+    pub fn params(mut self, params: Serialized) -> Self {
+        self.message.params = params;
+        self
+    }
+
+    /// Typed parameters (e.g `extras::multisig::ProposeParams`), serialized in CBOR
+    pub fn typed_params<P: serde::Serialize>(self, params: P) -> Result<Self, SignerError> {
+        let params = Serialized::serialize(params)
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+        Ok(self.params(params))
+    }
+
+    pub fn gas_limit(mut self, gas_limit: i64) -> Self {
+        self.message.gas_limit = gas_limit;
+        self
+    }
+
+    /// Gas fee cap in attoFIL
+    pub fn gas_fee_cap(mut self, gas_fee_cap: TokenAmount) -> Self {
+        self.message.gas_fee_cap = gas_fee_cap;
+        self
+    }
+
+    /// Gas premium in attoFIL
+    pub fn gas_premium(mut self, gas_premium: TokenAmount) -> Self {
+        self.message.gas_premium = gas_premium;
+        self
+    }
+
+    /// Validate the fields and return the message
+    pub fn build(self) -> Result<TypedMessage, SignerError> {
+        self.message.validate()?;
+
+        Ok(self.message)
+    }
+}
+
+impl TryFrom<&UnsignedMessageAPI> for TypedMessage {
+    type Error = SignerError;
+
+    fn try_from(message_api: &UnsignedMessageAPI) -> Result<TypedMessage, Self::Error> {
+        let to = Address::from_str(&message_api.to)
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+        let from = Address::from_str(&message_api.from)
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+        let params = base64::decode(&message_api.params)
+            .map_err(|err| SignerError::GenericString(err.to_string()))?;
+
+        TypedMessageBuilder::new(from, to)
+            .nonce(message_api.nonce)
+            .value(BigInt::from_str(&message_api.value)?)
+            .method(message_api.method)
+            .params(Serialized::new(params))
+            .gas_limit(message_api.gas_limit)
+            .gas_fee_cap(BigInt::from_str(&message_api.gas_fee_cap)?)
+            .gas_premium(BigInt::from_str(&message_api.gas_premium)?)
+            .build()
+    }
+}
+
+// `UnsignedMessageAPI` has no version field, a `TypedMessage` is always of `MESSAGE_VERSION`
+impl From<&TypedMessage> for UnsignedMessageAPI {
+    fn from(message: &TypedMessage) -> UnsignedMessageAPI {
+        UnsignedMessageAPI {
+            to: message.to.to_string(),
+            from: message.from.to_string(),
+            nonce: message.nonce,
+            value: message.value.to_string(),
+            gas_limit: message.gas_limit,
+            gas_fee_cap: message.gas_fee_cap.to_string(),
+            gas_premium: message.gas_premium.to_string(),
+            method: message.method,
+            params: base64::encode(message.params.bytes()),
+        }
+    }
+}
+
+impl TryFrom<&UnsignedMessage> for TypedMessage {
+    type Error = SignerError;
+
+    fn try_from(unsigned_message: &UnsignedMessage) -> Result<TypedMessage, Self::Error> {
+        TypedMessageBuilder::new(*unsigned_message.from(), *unsigned_message.to())
+            .version(message_version(unsigned_message)?)
+            .nonce(unsigned_message.sequence())
+            .value(unsigned_message.value().clone())
+            .method(unsigned_message.method_num())
+            .params(unsigned_message.params().clone())
+            .gas_limit(unsigned_message.gas_limit())
+            .gas_fee_cap(unsigned_message.gas_fee_cap().clone())
+            .gas_premium(unsigned_message.gas_premium().clone())
+            .build()
+    }
+}
+
+impl TryFrom<&TypedMessage> for UnsignedMessage {
+    type Error = SignerError;
+
+    fn try_from(message: &TypedMessage) -> Result<UnsignedMessage, Self::Error> {
+        UnsignedMessage::builder()
+            .version(message.version)
+            .to(message.to)
+            .from(message.from)
+            .sequence(message.nonce)
+            .value(message.value.clone())
+            .method_num(message.method)
+            .params(message.params.clone())
+            .gas_limit(message.gas_limit)
+            .gas_fee_cap(message.gas_fee_cap.clone())
+            .gas_premium(message.gas_premium.clone())
+            .build()
+            .map_err(SignerError::GenericString)
+    }
+}
+
+// forest doesn't expose the version of a message, it is the first field of its CBOR encoding
+fn message_version(unsigned_message: &UnsignedMessage) -> Result<i64, SignerError> {
+    let message_cbor = to_vec(unsigned_message)?;
+
+    match serde_cbor::from_slice::<serde_cbor::Value>(&message_cbor)? {
+        serde_cbor::Value::Array(fields) => match fields.first() {
+            Some(serde_cbor::Value::Integer(version)) => i64::try_from(*version)
+                .map_err(|_| SignerError::GenericString("Invalid message version".to_string())),
+            _ => Err(SignerError::GenericString(
+                "Invalid message version".to_string(),
+            )),
+        },
+        _ => Err(SignerError::GenericString(
+            "Invalid message encoding".to_string(),
+        )),
+    }
+}
+
+/// Check the fields of a message before signing it
+///
+/// Errors are messages the network refuses or that can only fail on chain, warnings are likely
+/// mistakes. Params are decoded for the method of `actor_type` (e.g "fil/5/multisig") when it is
+/// given, or of the Init actor when the message is sent to it; other params must at least be CBOR.
+///
+/// # Arguments
+///
+/// * `message` - The unsigned message to check
+/// * `actor_type` - The actor type of `to`, if known
+///
+pub fn validate(message: &UnsignedMessageAPI, actor_type: Option<&str>) -> MessageValidationAPI {
+    let mut validation = MessageValidationAPI::default();
+
+    let to = validate_address(&mut validation, "to", &message.to);
+    let from = validate_address(&mut validation, "from", &message.from);
+    // Builders write actor ID addresses (e.g the Init actor) with the mainnet prefix
+    if let (Some(to), Some(from)) = (&to, &from) {
+        if to.protocol() != Protocol::ID && to.network() != from.network() {
+            validation.warnings.push(issue(
+                "to",
+                MessageIssueKind::NetworkMismatch,
+                "`to` and `from` are not on the same network".to_string(),
+            ));
+        }
+    }
+
+    if let Some(value) = validate_amount(&mut validation, "value", &message.value) {
+        if value > fil_total_supply() {
+            validation.errors.push(issue(
+                "value",
+                MessageIssueKind::ValueAboveSupply,
+                "Value exceeds the total supply of FIL".to_string(),
+            ));
+        }
+    }
+
+    if message.gas_limit <= 0 || message.gas_limit > BLOCK_GAS_LIMIT {
+        validation.errors.push(issue(
+            "gas_limit",
+            MessageIssueKind::InvalidGasLimit,
+            format!("Gas limit must be between 1 and {}", BLOCK_GAS_LIMIT),
+        ));
+    }
+
+    let gas_fee_cap = validate_amount(&mut validation, "gas_fee_cap", &message.gas_fee_cap);
+    let gas_premium = validate_amount(&mut validation, "gas_premium", &message.gas_premium);
+    if let Some(gas_fee_cap) = &gas_fee_cap {
+        if gas_fee_cap.is_zero() {
+            validation.warnings.push(issue(
+                "gas_fee_cap",
+                MessageIssueKind::ZeroGasFeeCap,
+                "A gas fee cap of 0 can't pay the base fee, the message won't be included"
+                    .to_string(),
+            ));
+        }
+
+        if let Some(gas_premium) = &gas_premium {
+            if gas_premium > gas_fee_cap {
+                validation.errors.push(issue(
+                    "gas_premium",
+                    MessageIssueKind::GasPremiumAboveFeeCap,
+                    "Gas premium is greater than the gas fee cap".to_string(),
+                ));
+            }
+        }
+    }
+
+    validate_params(&mut validation, message, to.as_ref(), actor_type);
+
+    validation
+}
+
+fn issue(field: &str, kind: MessageIssueKind, message: String) -> MessageIssueAPI {
+    MessageIssueAPI {
+        field: field.to_string(),
+        kind,
+        message,
+    }
+}
+
+fn validate_address(
+    validation: &mut MessageValidationAPI,
+    field: &str,
+    address: &str,
+) -> Option<Address> {
+    match Address::from_str(address) {
+        Ok(address) => Some(address),
+        Err(err) => {
+            validation.errors.push(issue(
+                field,
+                MessageIssueKind::InvalidAddress,
+                format!("Invalid address `{}`: {}", address, err),
+            ));
+            None
+        }
+    }
+}
+
+fn validate_amount(
+    validation: &mut MessageValidationAPI,
+    field: &str,
+    amount: &str,
+) -> Option<TokenAmount> {
+    match BigInt::from_str(amount) {
+        Ok(amount) if amount < BigInt::zero() => {
+            validation.errors.push(issue(
+                field,
+                MessageIssueKind::NegativeAmount,
+                format!("`{}` can't be negative", field),
+            ));
+            None
+        }
+        Ok(amount) => Some(amount),
+        Err(_) => {
+            validation.errors.push(issue(
+                field,
+                MessageIssueKind::InvalidAmount,
+                format!("`{}` isn't an amount in attoFIL", amount),
+            ));
+            None
+        }
+    }
+}
+
+fn validate_params(
+    validation: &mut MessageValidationAPI,
+    message: &UnsignedMessageAPI,
+    to: Option<&Address>,
+    actor_type: Option<&str>,
+) {
+    let params = match base64::decode(&message.params) {
+        Ok(params) => params,
+        Err(err) => {
+            validation.errors.push(issue(
+                "params",
+                MessageIssueKind::InvalidParams,
+                format!("Params aren't base64: {}", err),
+            ));
+            return;
+        }
+    };
+
+    if message.method == METHOD_SEND {
+        if !params.is_empty() {
+            validation.warnings.push(issue(
+                "params",
+                MessageIssueKind::UnexpectedParams,
+                "A send doesn't take params".to_string(),
+            ));
+        }
+        return;
+    }
+
+    let to_init_actor = to.map_or(false, |to| to.payload() == INIT_ACTOR_ADDR.payload());
+    let actor_type = match actor_type {
+        None if to_init_actor => Some("fil/5/init"),
+        actor_type => actor_type,
+    };
+
+    match actor_type {
+        Some(actor_type) => {
+            if let Err(err) = deserialize_params(
+                message.params.clone(),
+                actor_type.to_string(),
+                message.method,
+            ) {
+                validation.errors.push(issue(
+                    "params",
+                    MessageIssueKind::InvalidParams,
+                    format!(
+                        "Params don't decode for method {} of {}: {}",
+                        message.method, actor_type, err
+                    ),
+                ));
+            }
+        }
+        None if !params.is_empty() => {
+            if let Err(err) = serde_cbor::from_slice::<serde_cbor::Value>(&params) {
+                validation.errors.push(issue(
+                    "params",
+                    MessageIssueKind::InvalidParams,
+                    format!("Params aren't CBOR: {}", err),
+                ));
+            }
+        }
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use forest_address::Address;
+    use forest_message::UnsignedMessage;
+    use forest_vm::Serialized;
+    use num_bigint_chainsafe::BigInt;
+
+    use extras::multisig;
+
+    use crate::api::{MessageIssueKind, MessageValidationAPI, UnsignedMessageAPI};
+    use crate::message::{
+        fil_total_supply, validate, TypedMessage, BLOCK_GAS_LIMIT, FIL_TOTAL_SUPPLY,
+    };
+    use crate::transaction_serialize;
+
+    const FROM: &str = "f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba";
+    const TO: &str = "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy";
+
+    fn message() -> TypedMessage {
+        TypedMessage::builder(
+            Address::from_str(FROM).unwrap(),
+            Address::from_str(TO).unwrap(),
+        )
+        .nonce(1)
+        .value(BigInt::from(100000))
+        .gas_limit(25000)
+        .gas_fee_cap(BigInt::from(2500))
+        .gas_premium(BigInt::from(2500))
+        .build()
+        .unwrap()
+    }
+
+    #[test]
+    fn convert_to_and_from_api() {
+        let message = message();
+        let message_api = UnsignedMessageAPI::from(&message);
+
+        assert_eq!(
+            message_api,
+            UnsignedMessageAPI {
+                to: TO.to_string(),
+                from: FROM.to_string(),
+                nonce: 1,
+                value: "100000".to_string(),
+                gas_limit: 25000,
+                gas_fee_cap: "2500".to_string(),
+                gas_premium: "2500".to_string(),
+                method: 0,
+                params: "".to_string(),
+            }
+        );
+        assert_eq!(TypedMessage::try_from(&message_api).unwrap(), message);
+    }
+
+    #[test]
+    fn convert_to_and_from_forest() {
+        let message = message();
+        let unsigned_message = UnsignedMessage::try_from(&message).unwrap();
+
+        assert_eq!(TypedMessage::try_from(&unsigned_message).unwrap(), message);
+        assert_eq!(
+            forest_encoding::to_vec(&unsigned_message).unwrap(),
+            transaction_serialize(&UnsignedMessageAPI::from(&message))
+                .unwrap()
+                .0
+        );
+
+        // A version the network doesn't support is rejected
+        let unsigned_message = UnsignedMessage::builder()
+            .version(1)
+            .to(*message.to())
+            .from(*message.from())
+            .build()
+            .unwrap();
+        assert!(TypedMessage::try_from(&unsigned_message).is_err());
+    }
+
+    #[test]
+    fn typed_params() {
+        let params = multisig::TxnIDParams {
+            id: multisig::TxnID(1),
+            proposal_hash: Vec::new(),
+        };
+
+        let message = message()
+            .to_builder()
+            .method(3)
+            .typed_params(params)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let message_api = UnsignedMessageAPI::from(&message);
+        assert_eq!(message_api.method, 3);
+        assert_eq!(TypedMessage::try_from(&message_api).unwrap(), message);
+    }
+
+    #[test]
+    fn total_supply() {
+        assert_eq!(fil_total_supply().to_string(), FIL_TOTAL_SUPPLY);
+    }
+
+    #[test]
+    fn validate_at_construction() {
+        let builder = message().to_builder();
+
+        assert!(builder.clone().version(1).build().is_err());
+        assert!(builder.clone().value(BigInt::from(-1)).build().is_err());
+        assert!(builder
+            .clone()
+            .value(BigInt::from_str(FIL_TOTAL_SUPPLY).unwrap() + 1)
+            .build()
+            .is_err());
+        assert!(builder.clone().gas_limit(-1).build().is_err());
+        assert!(builder
+            .clone()
+            .gas_limit(BLOCK_GAS_LIMIT + 1)
+            .build()
+            .is_err());
+        assert!(builder
+            .clone()
+            .gas_premium(BigInt::from(2501))
+            .build()
+            .is_err());
+
+        let mut message_api = UnsignedMessageAPI::from(&message());
+        message_api.gas_fee_cap = "-1".to_string();
+        assert!(TypedMessage::try_from(&message_api).is_err());
+    }
+
+    fn issues(validation: &MessageValidationAPI) -> (Vec<MessageIssueKind>, Vec<MessageIssueKind>) {
+        (
+            validation.errors.iter().map(|issue| issue.kind).collect(),
+            validation.warnings.iter().map(|issue| issue.kind).collect(),
+        )
+    }
+
+    #[test]
+    fn validate_message_fields() {
+        let mut message_api = UnsignedMessageAPI::from(&message());
+        message_api.from = "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba".to_string();
+        assert_eq!(
+            validate(&message_api, None),
+            MessageValidationAPI::default()
+        );
+
+        let check = |update: &dyn Fn(&mut UnsignedMessageAPI)| {
+            let mut message_api = message_api.clone();
+            update(&mut message_api);
+            issues(&validate(&message_api, None))
+        };
+
+        assert_eq!(
+            check(&|m| m.value = "-1".to_string()),
+            (vec![MessageIssueKind::NegativeAmount], vec![])
+        );
+        assert_eq!(
+            check(&|m| m.value = format!("{}1", FIL_TOTAL_SUPPLY)),
+            (vec![MessageIssueKind::ValueAboveSupply], vec![])
+        );
+        assert_eq!(
+            check(&|m| m.value = "1 FIL".to_string()),
+            (vec![MessageIssueKind::InvalidAmount], vec![])
+        );
+        assert_eq!(
+            check(&|m| m.gas_limit = 0),
+            (vec![MessageIssueKind::InvalidGasLimit], vec![])
+        );
+        assert_eq!(
+            check(&|m| m.gas_premium = "2501".to_string()),
+            (vec![MessageIssueKind::GasPremiumAboveFeeCap], vec![])
+        );
+        assert_eq!(
+            check(&|m| {
+                m.gas_fee_cap = "0".to_string();
+                m.gas_premium = "0".to_string();
+            }),
+            (vec![], vec![MessageIssueKind::ZeroGasFeeCap])
+        );
+        assert_eq!(
+            check(&|m| m.to = "t1invalid".to_string()),
+            (vec![MessageIssueKind::InvalidAddress], vec![])
+        );
+        assert_eq!(
+            check(&|m| m.from = FROM.to_string()),
+            (vec![], vec![MessageIssueKind::NetworkMismatch])
+        );
+    }
+
+    #[test]
+    fn validate_message_params() {
+        let mut message_api = UnsignedMessageAPI::from(&message());
+        message_api.from = "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba".to_string();
+
+        let propose_params = multisig::ProposeParams {
+            to: Address::from_str(TO).unwrap(),
+            value: BigInt::from(1000),
+            method: 0,
+            params: Serialized::new(Vec::new()),
+        };
+        let propose_params = Serialized::serialize(propose_params).unwrap();
+
+        // Params of a send are ignored
+        message_api.params = base64::encode(propose_params.bytes());
+        assert_eq!(
+            issues(&validate(&message_api, None)),
+            (vec![], vec![MessageIssueKind::UnexpectedParams])
+        );
+
+        message_api.method = 2;
+        assert!(validate(&message_api, Some("fil/5/multisig")).is_valid());
+        assert!(validate(&message_api, None).is_valid());
+
+        // Decoded as the `Exec` params of the Init actor
+        message_api.to = "t01".to_string();
+        assert_eq!(
+            issues(&validate(&message_api, None)),
+            (vec![MessageIssueKind::InvalidParams], vec![])
+        );
+
+        message_api.to = TO.to_string();
+        message_api.params = base64::encode(&[0xff, 0x00]);
+        assert_eq!(
+            issues(&validate(&message_api, None)),
+            (vec![MessageIssueKind::InvalidParams], vec![])
+        );
+
+        message_api.params = "not base64!".to_string();
+        assert!(!validate(&message_api, None).is_valid());
+    }
+}
@@ -0,0 +1,335 @@
+use bip39::{Language, MnemonicType, Seed};
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroize;
+
+use crate::error::SignerError;
+use crate::mnemonic_language;
+
+/// Language codes of the supported wordlists, in detection priority order
+pub const MNEMONIC_LANGUAGE_CODES: [&str; 8] =
+    ["en", "es", "fr", "it", "ja", "ko", "zh-hans", "zh-hant"];
+
+/// Maximum number of suggestions given for an invalid word
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Outcome of a mnemonic validation
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MnemonicStatus {
+    /// The mnemonic is valid
+    Valid,
+    /// None of the words belongs to a known wordlist
+    UnknownLanguage,
+    /// Some words do not belong to the wordlist
+    InvalidWords,
+    /// The number of words is not 12, 15, 18, 21 or 24
+    InvalidWordCount,
+    /// All the words are valid but the checksum does not match
+    InvalidChecksum,
+}
+
+/// Word of a mnemonic missing from the wordlist
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct InvalidMnemonicWord {
+    /// Position of the word in the mnemonic (starting at 0)
+    pub index: usize,
+    pub word: String,
+    /// Closest wordlist words, closest first
+    pub suggestions: Vec<String>,
+}
+
+/// Mnemonic validation report
+///
+/// The entropy and the seed are only given for a valid mnemonic.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MnemonicValidation {
+    pub status: MnemonicStatus,
+    /// Language code of the wordlist used (detected if not specified)
+    pub language_code: Option<String>,
+    pub word_count: usize,
+    pub invalid_words: Vec<InvalidMnemonicWord>,
+    pub entropy_hexstring: Option<String>,
+    pub seed_hexstring: Option<String>,
+}
+
+impl MnemonicValidation {
+    /// Validate a mnemonic
+    ///
+    /// # Arguments
+    ///
+    /// * `mnemonic` - the mnemonic words
+    /// * `password` - password used to compute the seed
+    /// * `language_code` - language of the mnemonic, detected from the words if `None`
+    ///
+    pub fn validate(
+        mnemonic: &str,
+        password: &str,
+        language_code: Option<&str>,
+    ) -> Result<Self, SignerError> {
+        let words: Vec<String> = mnemonic
+            .split_whitespace()
+            .map(|word| word.nfkd().collect())
+            .collect();
+
+        let (language_code, language) = match language_code {
+            Some(language_code) => (
+                language_code.to_ascii_lowercase(),
+                mnemonic_language(language_code)?,
+            ),
+            None => match detect_language(&words) {
+                Some(detected) => detected,
+                None => {
+                    return Ok(MnemonicValidation {
+                        status: MnemonicStatus::UnknownLanguage,
+                        language_code: None,
+                        word_count: words.len(),
+                        invalid_words: Vec::new(),
+                        entropy_hexstring: None,
+                        seed_hexstring: None,
+                    })
+                }
+            },
+        };
+
+        let invalid_words: Vec<InvalidMnemonicWord> = words
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| !is_valid_word(word, language))
+            .map(|(index, word)| InvalidMnemonicWord {
+                index,
+                word: word.clone(),
+                suggestions: suggest_words(word, language),
+            })
+            .collect();
+
+        let mut validation = MnemonicValidation {
+            status: MnemonicStatus::Valid,
+            language_code: Some(language_code),
+            word_count: words.len(),
+            invalid_words,
+            entropy_hexstring: None,
+            seed_hexstring: None,
+        };
+
+        if !validation.invalid_words.is_empty() {
+            validation.status = MnemonicStatus::InvalidWords;
+        } else if MnemonicType::for_word_count(words.len()).is_err() {
+            validation.status = MnemonicStatus::InvalidWordCount;
+        } else {
+            match bip39::Mnemonic::from_phrase(&words.join(" "), language) {
+                Ok(mnemonic) => {
+                    let seed = Seed::new(&mnemonic, password);
+
+                    validation.entropy_hexstring = Some(hex::encode(mnemonic.entropy()));
+                    validation.seed_hexstring = Some(hex::encode(seed.as_bytes()));
+                }
+                Err(_) => validation.status = MnemonicStatus::InvalidChecksum,
+            }
+        }
+
+        Ok(validation)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.status == MnemonicStatus::Valid
+    }
+}
+
+impl Drop for MnemonicValidation {
+    fn drop(&mut self) {
+        self.entropy_hexstring.zeroize();
+        self.seed_hexstring.zeroize();
+    }
+}
+
+fn is_valid_word(word: &str, language: Language) -> bool {
+    language.wordmap().get_bits(word).is_ok()
+}
+
+// Pick the wordlist matching the most words. Several wordlists can share words (e.g english and
+// french, or both chinese ones), ties are resolved in favor of a wordlist giving a valid checksum.
+fn detect_language(words: &[String]) -> Option<(String, Language)> {
+    let mut candidates = Vec::new();
+    let mut best_count = 0;
+
+    for language_code in MNEMONIC_LANGUAGE_CODES.iter() {
+        let language = match Language::from_language_code(language_code) {
+            Some(language) => language,
+            None => continue,
+        };
+
+        let count = words
+            .iter()
+            .filter(|word| is_valid_word(word, language))
+            .count();
+
+        if count > best_count {
+            best_count = count;
+            candidates.clear();
+        }
+
+        if count == best_count && count > 0 {
+            candidates.push((language_code.to_string(), language));
+        }
+    }
+
+    let phrase = words.join(" ");
+
+    candidates
+        .iter()
+        .find(|(_, language)| bip39::Mnemonic::validate(&phrase, *language).is_ok())
+        .or_else(|| candidates.first())
+        .cloned()
+}
+
+// Wordlist words within a small edit distance, the allowed distance grows with the word length
+// so that short words do not match most of the wordlist
+fn suggest_words(word: &str, language: Language) -> Vec<String> {
+    let max_distance = std::cmp::min(2, word.chars().count() / 3);
+
+    if max_distance == 0 {
+        return Vec::new();
+    }
+
+    // An empty prefix gives the full wordlist
+    let mut suggestions: Vec<(usize, &str)> = language
+        .wordlist()
+        .get_words_by_prefix("")
+        .iter()
+        .map(|candidate| (edit_distance(word, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+
+    suggestions.sort();
+
+    suggestions
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.to_string())
+        .collect()
+}
+
+// Optimal string alignment distance: insertions, deletions, substitutions and transpositions of
+// adjacent characters all count as one edit
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::key_generate_mnemonic_from_entropy;
+    use crate::mnemonic::{edit_distance, MnemonicStatus, MnemonicValidation};
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("abandon", "abandon"), 0);
+        assert_eq!(edit_distance("abandn", "abandon"), 1);
+        assert_eq!(edit_distance("abuot", "about"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "zoo"), 3);
+    }
+
+    #[test]
+    fn valid_mnemonic() {
+        let validation = MnemonicValidation::validate(MNEMONIC, "TREZOR", None).unwrap();
+
+        assert!(validation.is_valid());
+        assert_eq!(validation.language_code.as_deref(), Some("en"));
+        assert_eq!(validation.word_count, 12);
+        assert_eq!(
+            validation.entropy_hexstring.as_deref(),
+            Some("00000000000000000000000000000000")
+        );
+        // BIP39 test vector
+        assert_eq!(
+            validation.seed_hexstring.as_deref(),
+            Some("c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04")
+        );
+    }
+
+    #[test]
+    fn detect_language() {
+        for language_code in &["fr", "ja", "zh-hant", "ko"] {
+            let mnemonic = key_generate_mnemonic_from_entropy(&[7u8; 32], language_code).unwrap();
+            let validation = MnemonicValidation::validate(&mnemonic.0, "", None).unwrap();
+
+            assert!(validation.is_valid());
+            assert_eq!(validation.language_code.as_deref(), Some(*language_code));
+        }
+
+        let validation = MnemonicValidation::validate("hello world", "", Some("ja")).unwrap();
+        assert_eq!(validation.status, MnemonicStatus::InvalidWords);
+        assert_eq!(validation.language_code.as_deref(), Some("ja"));
+
+        let validation = MnemonicValidation::validate("xyzzy plugh", "", None).unwrap();
+        assert_eq!(validation.status, MnemonicStatus::UnknownLanguage);
+
+        assert!(MnemonicValidation::validate(MNEMONIC, "", Some("xx")).is_err());
+    }
+
+    #[test]
+    fn invalid_words() {
+        let mnemonic = MNEMONIC
+            .replacen("abandon", "abandn", 1)
+            .replace("about", "abuot");
+        let validation = MnemonicValidation::validate(&mnemonic, "", None).unwrap();
+
+        assert_eq!(validation.status, MnemonicStatus::InvalidWords);
+        assert_eq!(validation.language_code.as_deref(), Some("en"));
+        assert!(validation.seed_hexstring.is_none());
+
+        assert_eq!(validation.invalid_words.len(), 2);
+        assert_eq!(validation.invalid_words[0].index, 0);
+        assert_eq!(validation.invalid_words[0].word, "abandn");
+        assert_eq!(validation.invalid_words[0].suggestions[0], "abandon");
+        assert_eq!(validation.invalid_words[1].index, 11);
+        assert!(validation.invalid_words[1]
+            .suggestions
+            .contains(&"about".to_string()));
+    }
+
+    #[test]
+    fn invalid_word_count_and_checksum() {
+        let validation =
+            MnemonicValidation::validate(&MNEMONIC.replace(" about", ""), "", None).unwrap();
+        assert_eq!(validation.status, MnemonicStatus::InvalidWordCount);
+        assert_eq!(validation.word_count, 11);
+
+        let validation =
+            MnemonicValidation::validate(&MNEMONIC.replace("about", "abandon"), "", None).unwrap();
+        assert_eq!(validation.status, MnemonicStatus::InvalidChecksum);
+        assert!(validation.entropy_hexstring.is_none());
+        assert!(validation.seed_hexstring.is_none());
+    }
+}
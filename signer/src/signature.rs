@@ -1,5 +1,6 @@
 use core::{convert::TryFrom, fmt};
 use secp256k1::util::SIGNATURE_SIZE;
+use secp256k1::RecoveryId;
 
 use crate::error::SignerError;
 
@@ -35,6 +36,95 @@ impl SignatureSECP256K1 {
     pub fn as_bytes(&self) -> Vec<u8> {
         self.0.to_vec()
     }
+
+    /// Build a signature from its compact `r || s` encoding and a recovery ID
+    pub fn from_compact(
+        compact: &[u8],
+        recovery_id: u8,
+    ) -> Result<SignatureSECP256K1, SignerError> {
+        if compact.len() != SIGNATURE_SIZE {
+            return Err(SignerError::GenericString(
+                "Invalid Signature Length".to_string(),
+            ));
+        }
+
+        let mut sig = SignatureSECP256K1 {
+            0: [0; SIGNATURE_RECOVERY_SIZE],
+        };
+        sig.0[..SIGNATURE_SIZE].copy_from_slice(compact);
+        sig.0[SIGNATURE_SIZE] = RecoveryId::parse(recovery_id)?.serialize();
+
+        sig.parse_rs()?;
+
+        Ok(sig)
+    }
+
+    /// Build a signature from its DER encoding and a recovery ID
+    pub fn from_der(der: &[u8], recovery_id: u8) -> Result<SignatureSECP256K1, SignerError> {
+        let signature = secp256k1::Signature::parse_der(der)?;
+
+        SignatureSECP256K1::from_compact(&signature.serialize(), recovery_id)
+    }
+
+    /// Compact `r || s` encoding, the recovery ID is dropped
+    pub fn to_compact(&self) -> [u8; SIGNATURE_SIZE] {
+        let mut compact = [0; SIGNATURE_SIZE];
+        compact.copy_from_slice(&self.0[..SIGNATURE_SIZE]);
+
+        compact
+    }
+
+    /// DER encoding, the recovery ID is dropped
+    pub fn to_der(&self) -> Result<Vec<u8>, SignerError> {
+        let signature = self.parse_rs()?;
+
+        Ok(signature.serialize_der().as_ref().to_vec())
+    }
+
+    /// Recovery ID (`v`)
+    pub fn recovery_id(&self) -> u8 {
+        self.0[SIGNATURE_SIZE]
+    }
+
+    /// Whether the signature is canonical: `r` and `s` in `[1, n - 1]`, `s` in the lower half of the
+    /// curve order and a recovery ID in `[0, 3]`
+    pub fn is_canonical(&self) -> bool {
+        match self.parse_rs() {
+            Ok(signature) => {
+                !signature.s.is_high() && RecoveryId::parse(self.recovery_id()).is_ok()
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Same signature with a low `s`. A high `s` is negated, which flips the parity of the recovery
+    /// ID.
+    pub fn normalize_s(&self) -> Result<SignatureSECP256K1, SignerError> {
+        let mut signature = self.parse_rs()?;
+        let mut recovery_id = RecoveryId::parse(self.recovery_id())?.serialize();
+
+        if signature.s.is_high() {
+            signature.normalize_s();
+            recovery_id ^= 1;
+        }
+
+        SignatureSECP256K1::from_compact(&signature.serialize(), recovery_id)
+    }
+
+    // `secp256k1::Signature::parse` reduces `r` and `s` modulo the curve order, an out of range
+    // value doesn't survive the round trip
+    fn parse_rs(&self) -> Result<secp256k1::Signature, SignerError> {
+        let compact = self.to_compact();
+        let signature = secp256k1::Signature::parse(&compact);
+
+        if signature.r.is_zero() || signature.s.is_zero() || signature.serialize() != compact {
+            return Err(SignerError::GenericString(
+                "Invalid signature, r and s must be in [1, n - 1]".to_string(),
+            ));
+        }
+
+        Ok(signature)
+    }
 }
 
 impl TryFrom<Vec<u8>> for Signature {
@@ -152,3 +242,82 @@ impl AsRef<[u8]> for SignatureBLS {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use secp256k1::{sign, Message, SecretKey};
+
+    use crate::signature::SignatureSECP256K1;
+
+    fn signatures() -> (SignatureSECP256K1, SignatureSECP256K1) {
+        let secret_key = SecretKey::parse(&[7u8; 32]).unwrap();
+        let (mut signature, recovery_id) = sign(&Message::parse(&[9u8; 32]), &secret_key);
+
+        let low_s =
+            SignatureSECP256K1::from_compact(&signature.serialize(), recovery_id.serialize())
+                .unwrap();
+
+        signature.s = -&signature.s;
+        let high_s =
+            SignatureSECP256K1::from_compact(&signature.serialize(), recovery_id.serialize() ^ 1)
+                .unwrap();
+
+        (low_s, high_s)
+    }
+
+    #[test]
+    fn normalize_high_s() {
+        let (low_s, high_s) = signatures();
+
+        assert!(low_s.is_canonical());
+        assert!(!high_s.is_canonical());
+
+        assert_eq!(high_s.normalize_s().unwrap().0[..], low_s.0[..]);
+        assert_eq!(low_s.normalize_s().unwrap().0[..], low_s.0[..]);
+    }
+
+    #[test]
+    fn der_and_compact_round_trip() {
+        let (low_s, high_s) = signatures();
+
+        for signature in [low_s, high_s].iter() {
+            let der = signature.to_der().unwrap();
+            assert_eq!(der[0], 0x30);
+
+            let from_der = SignatureSECP256K1::from_der(&der, signature.recovery_id()).unwrap();
+            assert_eq!(from_der.0[..], signature.0[..]);
+
+            let from_compact =
+                SignatureSECP256K1::from_compact(&signature.to_compact(), signature.recovery_id())
+                    .unwrap();
+            assert_eq!(from_compact.0[..], signature.0[..]);
+        }
+    }
+
+    #[test]
+    fn reject_non_canonical() {
+        let (low_s, _) = signatures();
+
+        // r above the curve order
+        let mut compact = low_s.to_compact();
+        compact[..32].copy_from_slice(&[0xff; 32]);
+        assert!(SignatureSECP256K1::from_compact(&compact, 0).is_err());
+
+        let mut signature = SignatureSECP256K1 { 0: low_s.0 };
+        signature.0[..32].copy_from_slice(&[0xff; 32]);
+        assert!(!signature.is_canonical());
+        assert!(signature.to_der().is_err());
+        assert!(signature.normalize_s().is_err());
+
+        // s = 0
+        let mut signature = SignatureSECP256K1 { 0: low_s.0 };
+        signature.0[32..64].copy_from_slice(&[0; 32]);
+        assert!(!signature.is_canonical());
+
+        // Invalid recovery ID
+        assert!(SignatureSECP256K1::from_compact(&low_s.to_compact(), 4).is_err());
+        let mut signature = SignatureSECP256K1 { 0: low_s.0 };
+        signature.0[64] = 27;
+        assert!(!signature.is_canonical());
+    }
+}
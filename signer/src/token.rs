@@ -0,0 +1,394 @@
+use std::fmt;
+use std::str::FromStr;
+
+use num_bigint_chainsafe::BigInt;
+use num_traits::{One, Signed, Zero};
+
+use crate::error::SignerError;
+
+/// Denominations of FIL, a FIL is 10^18 attoFIL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilUnit {
+    Fil,
+    MilliFil,
+    MicroFil,
+    NanoFil,
+    PicoFil,
+    FemtoFil,
+    AttoFil,
+}
+
+impl FilUnit {
+    /// Number of decimals of the unit relative to attoFIL
+    pub fn decimals(self) -> usize {
+        match self {
+            FilUnit::Fil => 18,
+            FilUnit::MilliFil => 15,
+            FilUnit::MicroFil => 12,
+            FilUnit::NanoFil => 9,
+            FilUnit::PicoFil => 6,
+            FilUnit::FemtoFil => 3,
+            FilUnit::AttoFil => 0,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            FilUnit::Fil => "FIL",
+            FilUnit::MilliFil => "milliFIL",
+            FilUnit::MicroFil => "microFIL",
+            FilUnit::NanoFil => "nanoFIL",
+            FilUnit::PicoFil => "picoFIL",
+            FilUnit::FemtoFil => "femtoFIL",
+            FilUnit::AttoFil => "attoFIL",
+        }
+    }
+
+    fn scale(self) -> BigInt {
+        pow10(self.decimals())
+    }
+}
+
+impl FromStr for FilUnit {
+    type Err = SignerError;
+
+    /// Unit names are case insensitive (e.g "FIL", "nanofil", "attoFIL")
+    fn from_str(unit: &str) -> Result<Self, Self::Err> {
+        match unit.to_lowercase().as_str() {
+            "fil" => Ok(FilUnit::Fil),
+            "millifil" => Ok(FilUnit::MilliFil),
+            "microfil" => Ok(FilUnit::MicroFil),
+            "nanofil" => Ok(FilUnit::NanoFil),
+            "picofil" => Ok(FilUnit::PicoFil),
+            "femtofil" => Ok(FilUnit::FemtoFil),
+            "attofil" => Ok(FilUnit::AttoFil),
+            _ => Err(SignerError::GenericString(format!(
+                "Unknown FIL unit `{}`",
+                unit
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for FilUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// How to round an amount formatted with fewer decimals than it has
+///
+/// Rounding is symmetric: negative amounts are rounded like their absolute value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Towards zero
+    Down,
+    /// Away from zero
+    Up,
+    /// To the nearest, halves away from zero
+    HalfUp,
+    /// To the nearest, halves to the even neighbour
+    HalfEven,
+}
+
+/// Amount of FIL, held exactly in attoFIL
+///
+/// Parsed from a decimal number followed by an optional unit (e.g "1.5 FIL", "200 nanoFIL",
+/// "12 attoFIL"). A number without unit is in attoFIL, as the `value` of a message.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FilAmount(BigInt);
+
+impl FilAmount {
+    pub fn from_atto(atto: BigInt) -> Self {
+        FilAmount(atto)
+    }
+
+    /// Parse a decimal number expressed in `unit`
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - A decimal number (e.g "1.5", "-0.001", "200")
+    /// * `unit` - The unit of the number
+    ///
+    pub fn parse_in(number: &str, unit: FilUnit) -> Result<Self, SignerError> {
+        let invalid = || SignerError::GenericString(format!("Invalid FIL amount `{}`", number));
+
+        let (negative, digits) = match number.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, number),
+        };
+
+        let (integer, fraction) = match digits.find('.') {
+            Some(index) => (&digits[..index], &digits[index + 1..]),
+            None => (digits, ""),
+        };
+
+        if (integer.is_empty() && fraction.is_empty())
+            || !integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+            || digits.ends_with('.')
+        {
+            return Err(invalid());
+        }
+
+        // Trailing zeros don't change the amount, any other digit below attoFIL does
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > unit.decimals() {
+            return Err(SignerError::GenericString(format!(
+                "`{}` {} is not a whole number of attoFIL",
+                number, unit
+            )));
+        }
+
+        let atto_digits = format!(
+            "{}{}{}",
+            integer,
+            fraction,
+            "0".repeat(unit.decimals() - fraction.len())
+        );
+        let atto = BigInt::parse_bytes(atto_digits.as_bytes(), 10).ok_or_else(invalid)?;
+
+        Ok(FilAmount(if negative { -atto } else { atto }))
+    }
+
+    pub fn atto(&self) -> &BigInt {
+        &self.0
+    }
+
+    pub fn into_atto(self) -> BigInt {
+        self.0
+    }
+
+    /// Amount in attoFIL as a decimal string, the format of the message fields
+    pub fn to_atto_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Round the amount to `precision` decimals of `unit`
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - The unit the precision applies to
+    /// * `precision` - Number of decimals to keep
+    /// * `rounding` - How to round the dropped decimals
+    ///
+    pub fn round(&self, unit: FilUnit, precision: usize, rounding: Rounding) -> FilAmount {
+        if precision >= unit.decimals() {
+            return self.clone();
+        }
+
+        let step = pow10(unit.decimals() - precision);
+        let magnitude = self.0.abs();
+        let mut quotient = &magnitude / &step;
+        let remainder = &magnitude % &step;
+        let twice_remainder = &remainder * BigInt::from(2);
+
+        let round_up = match rounding {
+            Rounding::Down => false,
+            Rounding::Up => !remainder.is_zero(),
+            Rounding::HalfUp => twice_remainder >= step,
+            Rounding::HalfEven => {
+                twice_remainder > step
+                    || (twice_remainder == step && !(&quotient % BigInt::from(2)).is_zero())
+            }
+        };
+        if round_up {
+            quotient += BigInt::one();
+        }
+
+        let rounded = quotient * step;
+        FilAmount(if self.0.is_negative() {
+            -rounded
+        } else {
+            rounded
+        })
+    }
+
+    /// Format the amount in `unit`, followed by the unit name (e.g "1.5 FIL")
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - The unit to express the amount in
+    /// * `precision` - Number of decimals to show, `None` shows the exact amount without
+    ///   trailing zeros
+    /// * `rounding` - How to round the decimals beyond `precision`
+    ///
+    pub fn format(&self, unit: FilUnit, precision: Option<usize>, rounding: Rounding) -> String {
+        let amount = match precision {
+            Some(precision) => self.round(unit, precision, rounding),
+            None => self.clone(),
+        };
+
+        let scale = unit.scale();
+        let magnitude = amount.0.abs();
+        let integer = &magnitude / &scale;
+        let fraction = format!(
+            "{:0>width$}",
+            (&magnitude % &scale).to_string(),
+            width = unit.decimals()
+        );
+
+        let fraction = match precision {
+            Some(precision) if precision <= unit.decimals() => fraction[..precision].to_string(),
+            Some(precision) => format!("{:0<width$}", fraction, width = precision),
+            None => fraction.trim_end_matches('0').to_string(),
+        };
+
+        let sign = if amount.0.is_negative() { "-" } else { "" };
+        if fraction.is_empty() {
+            format!("{}{} {}", sign, integer, unit)
+        } else {
+            format!("{}{}.{} {}", sign, integer, fraction, unit)
+        }
+    }
+}
+
+impl FromStr for FilAmount {
+    type Err = SignerError;
+
+    /// Parse a decimal number followed by an optional unit, without unit the number is in attoFIL
+    fn from_str(amount: &str) -> Result<Self, Self::Err> {
+        let amount = amount.trim();
+        let (number, unit) = match amount.find(|c: char| c.is_ascii_alphabetic()) {
+            Some(index) => (amount[..index].trim_end(), amount[index..].parse()?),
+            None => (amount, FilUnit::AttoFil),
+        };
+
+        FilAmount::parse_in(number, unit)
+    }
+}
+
+impl fmt::Display for FilAmount {
+    /// Exact amount in FIL (e.g "1.5 FIL")
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format(FilUnit::Fil, None, Rounding::Down))
+    }
+}
+
+impl From<BigInt> for FilAmount {
+    fn from(atto: BigInt) -> Self {
+        FilAmount(atto)
+    }
+}
+
+impl From<FilAmount> for BigInt {
+    fn from(amount: FilAmount) -> Self {
+        amount.0
+    }
+}
+
+fn pow10(exponent: usize) -> BigInt {
+    num_traits::pow(BigInt::from(10), exponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use num_bigint_chainsafe::BigInt;
+
+    use crate::token::{FilAmount, FilUnit, Rounding};
+
+    fn atto(amount: &str) -> String {
+        FilAmount::from_str(amount).unwrap().to_atto_string()
+    }
+
+    #[test]
+    fn parse_units() {
+        assert_eq!(atto("1.5 FIL"), "1500000000000000000");
+        assert_eq!(atto("1.5FIL"), "1500000000000000000");
+        assert_eq!(atto("200 nanoFIL"), "200000000000");
+        assert_eq!(atto("200 nanofil"), "200000000000");
+        assert_eq!(atto("12 attoFIL"), "12");
+        assert_eq!(atto("0.000000000000000001 FIL"), "1");
+        assert_eq!(atto("-0.25 milliFIL"), "-250000000000000");
+        assert_eq!(atto(".5 microFIL"), "500000000000");
+        assert_eq!(atto("1.500 FIL"), "1500000000000000000");
+
+        // Without unit the amount is in attoFIL
+        assert_eq!(atto("100000"), "100000");
+        assert_eq!(atto("0"), "0");
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for amount in &[
+            "",
+            "FIL",
+            "1.5 EUR",
+            "1.2.3 FIL",
+            "1. FIL",
+            "- FIL",
+            "+1 FIL",
+            "1e18",
+            "1,5 FIL",
+            "1.5 attoFIL",
+            "0.0000000000000000001 FIL",
+        ] {
+            assert!(FilAmount::from_str(amount).is_err(), "{}", amount);
+        }
+    }
+
+    #[test]
+    fn format_exact() {
+        let amount = FilAmount::from_str("1.5 FIL").unwrap();
+
+        assert_eq!(amount.to_string(), "1.5 FIL");
+        assert_eq!(
+            amount.format(FilUnit::NanoFil, None, Rounding::Down),
+            "1500000000 nanoFIL"
+        );
+        assert_eq!(
+            amount.format(FilUnit::Fil, Some(3), Rounding::Down),
+            "1.500 FIL"
+        );
+        assert_eq!(
+            amount.format(FilUnit::AttoFil, Some(2), Rounding::Down),
+            "1500000000000000000.00 attoFIL"
+        );
+        assert_eq!(FilAmount::default().to_string(), "0 FIL");
+        assert_eq!(
+            FilAmount::from(BigInt::from(-12)).to_string(),
+            "-0.000000000000000012 FIL"
+        );
+
+        let formatted = amount.format(FilUnit::MicroFil, None, Rounding::Down);
+        assert_eq!(FilAmount::from_str(&formatted).unwrap(), amount);
+    }
+
+    #[test]
+    fn format_rounding() {
+        let format = |amount: &str, rounding| {
+            FilAmount::from_str(amount)
+                .unwrap()
+                .format(FilUnit::Fil, Some(1), rounding)
+        };
+
+        assert_eq!(format("1.25 FIL", Rounding::Down), "1.2 FIL");
+        assert_eq!(format("1.21 FIL", Rounding::Up), "1.3 FIL");
+        assert_eq!(format("1.2 FIL", Rounding::Up), "1.2 FIL");
+        assert_eq!(format("1.25 FIL", Rounding::HalfUp), "1.3 FIL");
+        assert_eq!(format("1.249 FIL", Rounding::HalfUp), "1.2 FIL");
+        assert_eq!(format("1.25 FIL", Rounding::HalfEven), "1.2 FIL");
+        assert_eq!(format("1.35 FIL", Rounding::HalfEven), "1.4 FIL");
+        assert_eq!(format("1.251 FIL", Rounding::HalfEven), "1.3 FIL");
+        assert_eq!(format("-1.25 FIL", Rounding::HalfUp), "-1.3 FIL");
+        assert_eq!(format("-1.25 FIL", Rounding::Down), "-1.2 FIL");
+        assert_eq!(format("-0.01 FIL", Rounding::HalfUp), "0.0 FIL");
+        assert_eq!(format("0.96 FIL", Rounding::HalfUp), "1.0 FIL");
+
+        let amount = FilAmount::from_str("1.99 FIL").unwrap();
+        assert_eq!(
+            amount.format(FilUnit::Fil, Some(0), Rounding::Down),
+            "1 FIL"
+        );
+        assert_eq!(
+            amount
+                .round(FilUnit::Fil, 0, Rounding::HalfUp)
+                .to_atto_string(),
+            "2000000000000000000"
+        );
+    }
+}
@@ -1,8 +1,25 @@
 use blake2b_simd::Params;
 use core::{array::TryFromSliceError, convert::TryInto};
 
+use crate::error::SignerError;
+
 static CID_PREFIX: &[u8] = &[0x01, 0x71, 0xa0, 0xe4, 0x02, 0x20];
 
+/// Domain separation prefix of user-facing signed data, it can never start a CBOR message
+pub const SIGNED_MESSAGE_PREFIX: &[u8] = b"\x19Filecoin Signed Message:\n";
+
+/// Prefix data with `SIGNED_MESSAGE_PREFIX` and its length in decimal
+pub fn prefix_signed_message(data: &[u8]) -> Vec<u8> {
+    let length = data.len().to_string();
+
+    let mut prefixed = Vec::with_capacity(SIGNED_MESSAGE_PREFIX.len() + length.len() + data.len());
+    prefixed.extend_from_slice(SIGNED_MESSAGE_PREFIX);
+    prefixed.extend_from_slice(length.as_bytes());
+    prefixed.extend_from_slice(data);
+
+    prefixed
+}
+
 /// transform a message into a hashed message ready to be signed and following Filecoin standard
 pub fn get_digest(message: &[u8]) -> Result<[u8; 32], TryFromSliceError> {
     let message_hashed = Params::new()
@@ -32,9 +49,54 @@ pub fn get_digest_voucher(message: &[u8]) -> Result<[u8; 32], TryFromSliceError>
     message_hashed.as_bytes().try_into()
 }
 
+/// Encode an unsigned integer as LEB128 (unsigned varint)
+pub fn leb128_encode(mut value: u64) -> Vec<u8> {
+    let mut encoded = Vec::new();
+
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            encoded.push(byte);
+            return encoded;
+        }
+
+        encoded.push(byte | 0x80);
+    }
+}
+
+/// Decode a LEB128 (unsigned varint) prefix, returns the value and the number of bytes read
+pub fn leb128_decode(bytes: &[u8]) -> Result<(u64, usize), SignerError> {
+    let mut value: u64 = 0;
+
+    for (i, byte) in bytes.iter().enumerate() {
+        // A u64 holds at most 10 groups of 7 bits
+        if i >= 10 || (i == 9 && *byte > 1) {
+            return Err(SignerError::GenericString(
+                "Varint overflows u64".to_string(),
+            ));
+        }
+
+        value |= u64::from(byte & 0x7f) << (7 * i);
+
+        if byte & 0x80 == 0 {
+            if *byte == 0 && i > 0 {
+                return Err(SignerError::GenericString(
+                    "Varint is not minimally encoded".to_string(),
+                ));
+            }
+
+            return Ok((value, i + 1));
+        }
+    }
+
+    Err(SignerError::GenericString("Truncated varint".to_string()))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::utils::get_digest;
+    use crate::utils::{get_digest, leb128_decode, leb128_encode, prefix_signed_message};
     use hex::{decode, encode};
 
     #[test]
@@ -50,6 +112,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn signed_message_prefix() {
+        assert_eq!(
+            prefix_signed_message(b"filecoin"),
+            b"\x19Filecoin Signed Message:\n8filecoin".to_vec()
+        );
+        assert_eq!(
+            prefix_signed_message(b""),
+            b"\x19Filecoin Signed Message:\n0".to_vec()
+        );
+    }
+
+    #[test]
+    fn leb128() {
+        assert_eq!(leb128_encode(0), vec![0x00]);
+        assert_eq!(leb128_encode(10), vec![0x0a]);
+        assert_eq!(leb128_encode(300), vec![0xac, 0x02]);
+        assert_eq!(
+            leb128_decode(&leb128_encode(u64::MAX)).unwrap(),
+            (u64::MAX, 10)
+        );
+        assert_eq!(leb128_decode(&[0xac, 0x02, 0x05]).unwrap(), (300, 2));
+
+        assert!(leb128_decode(&[0xac]).is_err());
+        assert!(leb128_decode(&[0x80, 0x00]).is_err());
+        assert!(leb128_decode(&[0xff; 10]).is_err());
+    }
+
     #[test]
     fn empty() {
         // FIXME:
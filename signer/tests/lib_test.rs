@@ -1,4 +1,5 @@
 use std::convert::TryFrom;
+use std::str::FromStr;
 
 use bip39::{Language, Seed};
 use bls_signatures::Serialize;
@@ -9,8 +10,13 @@ use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 use rayon::prelude::*;
 
-use filecoin_signer::api::{MessageParams, MessageTxAPI, UnsignedMessageAPI};
-use filecoin_signer::signature::{Signature, SignatureBLS};
+use filecoin_signer::api::{
+    MessageIssueKind, MessageParams, MessageTxAPI, SignedMessageAPI, UnsignedMessageAPI,
+};
+use filecoin_signer::backend::{PrivateKeySigner, Signer};
+use filecoin_signer::extended_key::{ExtendedSecretKey, HARDENED_BIT};
+use filecoin_signer::keystore::{DecryptedKey, EncryptedKey};
+use filecoin_signer::signature::{Signature, SignatureBLS, SignatureSECP256K1};
 use filecoin_signer::*;
 
 use extras::multisig;
@@ -29,6 +35,78 @@ fn decode_key() {
     assert_eq!(base64::encode(&pk.0), private_key.to_string());
 }
 
+#[test]
+fn redacted_secrets() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let private_key = test_value["private_key"].as_str().unwrap();
+    let mnemonic = Mnemonic(test_value["mnemonic"].as_str().unwrap().to_string());
+
+    let extended_key = key_recover(
+        &PrivateKey::try_from(private_key.to_string()).unwrap(),
+        false,
+    )
+    .unwrap();
+    let private_hexstring = hex::encode(&extended_key.private_key.0);
+
+    let printed = format!(
+        "{:?} {} {:?} {}",
+        extended_key, extended_key, extended_key.private_key, extended_key.private_key
+    );
+    assert!(!printed.contains(private_key));
+    assert!(!printed.contains(&private_hexstring));
+    assert!(printed.contains(&extended_key.address));
+
+    let printed = format!("{:?} {}", mnemonic, mnemonic);
+    assert!(!printed.contains(&mnemonic.0[..5]));
+}
+
+#[test]
+fn serialize_secrets() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let private_key = test_value["private_key"].as_str().unwrap();
+    let bls_private_key = test_value["bls_private_key"].as_str().unwrap();
+    let mnemonic = test_value["mnemonic"].as_str().unwrap();
+
+    let pk = PrivateKey::try_from(private_key.to_string()).unwrap();
+    let pk_json = serde_json::to_string(&pk).unwrap();
+    assert_eq!(pk_json, format!("\"{}\"", private_key));
+    let pk_decoded: PrivateKey = serde_json::from_str(&pk_json).unwrap();
+    assert_eq!(pk_decoded.0, pk.0);
+
+    let mnemonic_json = serde_json::to_string(&Mnemonic(mnemonic.to_string())).unwrap();
+    let mnemonic_decoded: Mnemonic = serde_json::from_str(&mnemonic_json).unwrap();
+    assert_eq!(mnemonic_decoded.0, mnemonic);
+
+    let secp_key = key_recover(&pk, true).unwrap();
+    let bls_key = key_recover_bls(
+        &PrivateKey::try_from(bls_private_key.to_string()).unwrap(),
+        false,
+    )
+    .unwrap();
+
+    for extended_key in &[secp_key, bls_key] {
+        let json = serde_json::to_value(extended_key).unwrap();
+        assert_eq!(
+            json["private_base64"],
+            base64::encode(&extended_key.private_key.0)
+        );
+        assert_eq!(json["address"], extended_key.address);
+
+        let decoded: ExtendedKey = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(decoded.private_key.0, extended_key.private_key.0);
+        assert_eq!(
+            decoded.public_key.to_vec(),
+            extended_key.public_key.to_vec()
+        );
+        assert_eq!(decoded.address, extended_key.address);
+
+        // The address must belong to the private key
+        let mut tampered = json;
+        tampered["address"] = "f1rovwtiuo5ncslpmpjftzu5akswbgsgighjazxoi".into();
+        assert!(serde_json::from_value::<ExtendedKey>(tampered).is_err());
+    }
+}
+
 #[test]
 fn generate_mnemonic() {
     let mnemonic = key_generate_mnemonic().expect("could not generate mnemonic");
@@ -38,6 +116,107 @@ fn generate_mnemonic() {
     assert_eq!(word_count, 24)
 }
 
+#[test]
+fn generate_mnemonic_with_options() {
+    for word_count in &[12, 15, 18, 21, 24] {
+        let mnemonic = key_generate_mnemonic_with_options(*word_count, "fr").unwrap();
+
+        assert_eq!(mnemonic.0.split_whitespace().count(), *word_count);
+        assert!(bip39::Mnemonic::validate(&mnemonic.0, Language::French).is_ok());
+    }
+
+    assert!(key_generate_mnemonic_with_options(13, "en").is_err());
+    assert!(key_generate_mnemonic_with_options(12, "xx").is_err());
+}
+
+#[test]
+fn generate_mnemonic_from_rng() {
+    let mnemonic =
+        key_generate_mnemonic_from_rng(&mut ChaCha8Rng::seed_from_u64(0), 12, "en").unwrap();
+    let same_mnemonic =
+        key_generate_mnemonic_from_rng(&mut ChaCha8Rng::seed_from_u64(0), 12, "en").unwrap();
+
+    assert_eq!(
+        mnemonic.0,
+        "history system prefer soup joke surround tenant brand ripple wood face upset"
+    );
+    assert_eq!(mnemonic.0, same_mnemonic.0);
+}
+
+#[test]
+fn generate_mnemonic_from_entropy() {
+    let mnemonic = key_generate_mnemonic_from_entropy(&[0u8; 16], "en").unwrap();
+    assert_eq!(
+        mnemonic.0,
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    );
+
+    let entropy = hex::decode("6c3b9aa767f785b537c0d8ba5fa54677").unwrap();
+    let mnemonic = key_generate_mnemonic_from_entropy(&entropy, "en").unwrap();
+    assert_eq!(
+        mnemonic.0,
+        "history system prefer soup joke surround tenant brand ripple wood face upset"
+    );
+
+    assert!(key_generate_mnemonic_from_entropy(&[0u8; 15], "en").is_err());
+}
+
+#[test]
+fn generate_mnemonic_from_dice() {
+    let rolls = "12345612345612345612345612345612345612345612345612";
+
+    let entropy = mnemonic_entropy_from_dice(rolls, 12).unwrap();
+    assert_eq!(hex::encode(&entropy), "ee72ae915a4e6ea7ccbeb8e5e5eecef2");
+
+    let mnemonic = key_generate_mnemonic_from_entropy(&entropy, "en").unwrap();
+    assert_eq!(
+        mnemonic.0,
+        "unveil nice picture region tragic fault cream strike tourist control recipe tourist"
+    );
+
+    // Whitespaces are ignored
+    let spaced_rolls = rolls.replace("6", "6 ");
+    assert_eq!(
+        mnemonic_entropy_from_dice(&spaced_rolls, 12).unwrap(),
+        entropy
+    );
+
+    assert!(mnemonic_entropy_from_dice(&rolls[..49], 12).is_err());
+    assert!(mnemonic_entropy_from_dice(&rolls.replace("1", "7"), 12).is_err());
+    assert_eq!(
+        mnemonic_entropy_from_dice(&"1".repeat(100), 24)
+            .unwrap()
+            .len(),
+        32
+    );
+    assert!(mnemonic_entropy_from_dice(&"1".repeat(99), 24).is_err());
+}
+
+#[test]
+fn validate_mnemonic() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let mnemonic = test_value["mnemonic"].as_str().unwrap();
+
+    let validation = mnemonic_validate(mnemonic, "", None).unwrap();
+    assert!(validation.is_valid());
+    assert_eq!(validation.language_code.as_deref(), Some("en"));
+
+    let seed = Seed::new(
+        &bip39::Mnemonic::from_phrase(mnemonic, Language::English).unwrap(),
+        "",
+    );
+    assert_eq!(
+        validation.seed_hexstring.as_deref(),
+        Some(hex::encode(seed.as_bytes()).as_str())
+    );
+
+    let misspelled = mnemonic.replace("anxiety", "anxeity");
+    let validation = mnemonic_validate(&misspelled, "", Some("en")).unwrap();
+    assert!(!validation.is_valid());
+    assert_eq!(validation.invalid_words[0].index, 7);
+    assert_eq!(validation.invalid_words[0].suggestions[0], "anxiety");
+}
+
 #[test]
 fn derive_key() {
     let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
@@ -93,6 +272,186 @@ fn derive_key_from_seed() {
     );
 }
 
+#[test]
+fn derive_key_watch_only() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let mnemonic = test_value["mnemonic"].as_str().unwrap();
+    let language_code = test_value["language_code"].as_str().unwrap();
+
+    let m = bip39::Mnemonic::from_phrase(&mnemonic, Language::English).unwrap();
+    let seed = Seed::new(&m, "");
+
+    // Account level key for `m/44'/461'/0`
+    let account = ExtendedSecretKey::try_from(seed.as_bytes())
+        .unwrap()
+        .derive_child_key(44 | HARDENED_BIT)
+        .unwrap()
+        .derive_child_key(461 | HARDENED_BIT)
+        .unwrap()
+        .derive_child_key(0)
+        .unwrap();
+
+    let xpub = account.extended_public_key();
+
+    for child in 0..3 {
+        let path = format!("m/44'/461'/0/0/{}", child);
+        let extended_key = key_derive(&mnemonic, &path, "", language_code).unwrap();
+
+        let watch_only_key = key_derive_public(&xpub, &[0, child], false).unwrap();
+
+        assert_eq!(watch_only_key.address, extended_key.address);
+        assert_eq!(
+            watch_only_key.public_key.to_vec(),
+            extended_key.public_key.to_vec()
+        );
+    }
+
+    assert!(key_derive_public(&xpub, &[0, 1 | HARDENED_BIT], false).is_err());
+}
+
+#[test]
+fn derive_key_any_depth() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let mnemonic = test_value["mnemonic"].as_str().unwrap();
+    let language_code = test_value["language_code"].as_str().unwrap();
+
+    let extended_key = key_derive(&mnemonic, "m/44'/461'/0'/0/1", "", language_code).unwrap();
+    let extended_key_h = key_derive(&mnemonic, "m/44h/461h/0h/0/1", "", language_code).unwrap();
+    assert_eq!(extended_key.address, extended_key_h.address);
+
+    let account_key = key_derive(&mnemonic, "m/44'/461'/0'", "", language_code).unwrap();
+    assert_ne!(account_key.address, extended_key.address);
+
+    let testnet_key = key_derive(&mnemonic, "m/44'/1'/0'", "", language_code).unwrap();
+    assert!(testnet_key.address.starts_with('t'));
+
+    assert!(key_derive(&mnemonic, "m/44'/461'/0'/x", "", language_code).is_err());
+
+    // Relative paths are a mistake when deriving from the master key
+    assert!(key_derive(&mnemonic, "", "", language_code).is_err());
+    assert!(key_derive(&mnemonic, "0/1", "", language_code).is_err());
+    assert!(key_derive_from_seed(&[0u8; 32], "44'/461'/0/0/0").is_err());
+}
+
+#[test]
+fn derive_key_from_extended() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let mnemonic = test_value["mnemonic"].as_str().unwrap();
+    let language_code = test_value["language_code"].as_str().unwrap();
+    let master_key = test_value["master_key"].as_str().unwrap();
+
+    for child in 0..3 {
+        let path = format!("m/44'/461'/0/0/{}", child);
+        let extended_key = key_derive(&mnemonic, &path, "", language_code).unwrap();
+
+        let relative_path = format!("44'/461'/0/0/{}", child);
+        let imported_key = key_derive_from_extended(master_key, &relative_path).unwrap();
+
+        assert_eq!(imported_key.address, extended_key.address);
+        assert_eq!(imported_key.private_key.0, extended_key.private_key.0);
+    }
+
+    assert!(key_derive_from_extended(master_key, "44'/461'/a").is_err());
+}
+
+#[test]
+fn derive_key_public_from_extended() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let mnemonic = test_value["mnemonic"].as_str().unwrap();
+    let language_code = test_value["language_code"].as_str().unwrap();
+    let master_key = test_value["master_key"].as_str().unwrap();
+
+    let account = ExtendedSecretKey::from_base58(master_key)
+        .unwrap()
+        .derive_child_key(44 | HARDENED_BIT)
+        .unwrap()
+        .derive_child_key(1 | HARDENED_BIT)
+        .unwrap()
+        .derive_child_key(0)
+        .unwrap();
+
+    let tpub = account.extended_public_key().to_base58(true);
+    assert!(tpub.starts_with("tpub"));
+
+    let path = "m/44'/1'/0/0/1";
+    let extended_key = key_derive(&mnemonic, path, "", language_code).unwrap();
+
+    let watch_only_key = key_derive_public_from_extended(&tpub, "0/1").unwrap();
+
+    assert_eq!(watch_only_key.address, extended_key.address);
+    assert!(watch_only_key.address.starts_with('t'));
+
+    assert!(key_derive_public_from_extended(&tpub, "0/1'").is_err());
+    assert!(key_derive_public_from_extended(master_key, "0/1").is_err());
+}
+
+#[test]
+fn import_export_lotus_key() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let private_key = test_value["private_key"].as_str().unwrap();
+    let lotus_key = test_value["lotus_key"].as_str().unwrap();
+    let address = test_value["childs"][3]["address"].as_str().unwrap();
+
+    let extended_key = key_import_lotus(lotus_key, false).unwrap();
+
+    assert_eq!(base64::encode(&extended_key.private_key.0), private_key);
+    assert_eq!(extended_key.address, address);
+    assert_eq!(key_export_lotus(&extended_key).unwrap(), lotus_key);
+}
+
+#[test]
+fn import_export_lotus_key_bls() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let bls_private_key = test_value["bls_private_key"].as_str().unwrap();
+    let bls_lotus_key = test_value["bls_lotus_key"].as_str().unwrap();
+    let bls_address = test_value["bls_address"].as_str().unwrap();
+
+    let extended_key = key_import_lotus(bls_lotus_key, true).unwrap();
+
+    assert_eq!(base64::encode(&extended_key.private_key.0), bls_private_key);
+    assert_eq!(extended_key.address, bls_address);
+    assert_eq!(key_export_lotus(&extended_key).unwrap(), bls_lotus_key);
+
+    assert!(key_import_lotus("7b7d", true).is_err());
+    assert!(key_import_lotus("not hex", true).is_err());
+}
+
+#[test]
+fn keystore_decrypt_test_vectors() {
+    let test_value = common::load_test_vectors("../test_vectors/keystore.json").unwrap();
+    let passphrase = test_value["passphrase"].as_str().unwrap();
+
+    for key in test_value["keys"].as_array().unwrap() {
+        let encrypted_key: EncryptedKey =
+            serde_json::from_value(key["keystore"].to_owned()).unwrap();
+
+        match encrypted_key.decrypt(passphrase).unwrap() {
+            DecryptedKey::Key(extended_key) => {
+                assert_eq!(
+                    base64::encode(&extended_key.private_key.0),
+                    key["private_key"].as_str().unwrap()
+                );
+                assert_eq!(extended_key.address, key["address"].as_str().unwrap());
+            }
+            DecryptedKey::Mnemonic(_) => panic!("expected a key"),
+        }
+
+        assert!(encrypted_key.decrypt("wrong passphrase").is_err());
+    }
+
+    for mnemonic in test_value["mnemonics"].as_array().unwrap() {
+        let encrypted_key: EncryptedKey =
+            serde_json::from_value(mnemonic["keystore"].to_owned()).unwrap();
+
+        match encrypted_key.decrypt(passphrase).unwrap() {
+            DecryptedKey::Mnemonic(decrypted) => {
+                assert_eq!(decrypted.0, mnemonic["mnemonic"].as_str().unwrap())
+            }
+            DecryptedKey::Key(_) => panic!("expected a mnemonic"),
+        }
+    }
+}
+
 #[test]
 fn test_key_recover_testnet() {
     let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
@@ -133,6 +492,153 @@ fn test_key_recover_mainnet() {
     assert_eq!(&recovered_key.address, &address);
 }
 
+#[test]
+fn inspect_and_convert_address() {
+    use filecoin_signer::address::{AddressFormat, AddressNetwork, AddressProtocol};
+
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let address = test_value["childs"][3]["address"].as_str().unwrap();
+
+    let info = address_inspect(address).unwrap();
+
+    assert_eq!(info.protocol, AddressProtocol::Secp256k1);
+    assert_eq!(info.network, AddressNetwork::Mainnet);
+    assert!(info.checksum_valid);
+    assert_eq!(info.mainnet_address.as_deref(), Some(address));
+
+    let testnet_address = address_convert(address, AddressFormat::Testnet).unwrap();
+    assert_eq!(info.testnet_address, Some(testnet_address.clone()));
+    assert_eq!(
+        address_convert(&testnet_address, AddressFormat::Mainnet).unwrap(),
+        address
+    );
+
+    let bytes = address_convert(address, AddressFormat::Bytes).unwrap();
+    assert_eq!(bytes, info.bytes_hexstring);
+    assert_eq!(
+        address_from_bytes(&hex::decode(&bytes).unwrap(), true).unwrap(),
+        testnet_address
+    );
+
+    // Same payload as forest
+    assert_eq!(
+        Address::from_str(address).unwrap().to_bytes(),
+        hex::decode(&bytes).unwrap()
+    );
+}
+
+#[test]
+fn key_recover_delegated_address() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let private_key = test_value["private_key"].as_str().unwrap();
+    let bls_private_key = test_value["bls_private_key"].as_str().unwrap();
+
+    let pk = PrivateKey::try_from(private_key.to_string()).unwrap();
+    let recovered_key = key_recover(&pk, true).unwrap();
+
+    let eth_address = recovered_key.eth_address().unwrap().unwrap();
+    let delegated_address = recovered_key.delegated_address().unwrap().unwrap();
+
+    assert_eq!(eth_address, "0xE2f99104C3B7B50C1dE196ac98C9eadf389d455F");
+    assert_eq!(
+        delegated_address,
+        "t410f4l4zcbgdw62qyhpbs2wjrspk344j2rk7xe75ivi"
+    );
+
+    assert_eq!(
+        eth_address_to_delegated(&eth_address, true).unwrap(),
+        delegated_address
+    );
+    assert_eq!(
+        delegated_to_eth_address(&delegated_address).unwrap(),
+        eth_address
+    );
+    assert_eq!(
+        eth_address_to_delegated(&eth_address.to_lowercase(), false).unwrap(),
+        format!("f{}", &delegated_address[1..])
+    );
+
+    // A delegated address cannot sign Filecoin messages
+    let message = UnsignedMessageAPI {
+        to: "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy".to_string(),
+        from: delegated_address,
+        nonce: 1,
+        value: "100000".to_string(),
+        gas_limit: 25000,
+        gas_fee_cap: "2500".to_string(),
+        gas_premium: "2500".to_string(),
+        method: 0,
+        params: "".to_string(),
+    };
+    assert!(transaction_sign(&message, &pk).is_err());
+
+    let bls_pk = PrivateKey::try_from(bls_private_key.to_string()).unwrap();
+    let bls_key = key_recover_bls(&bls_pk, true).unwrap();
+    assert!(bls_key.eth_address().unwrap().is_none());
+    assert!(bls_key.delegated_address().unwrap().is_none());
+}
+
+#[test]
+fn derive_key_bls() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let mnemonic = test_value["mnemonic"].as_str().unwrap();
+    let language_code = test_value["language_code"].as_str().unwrap();
+    let path = "m/12381/461/0/0";
+
+    let extended_key = key_derive_bls(&mnemonic, path, "", language_code).unwrap();
+
+    let m = bip39::Mnemonic::from_phrase(&mnemonic, Language::English).unwrap();
+    let seed = Seed::new(&m, "");
+    let extended_key_expected = key_derive_bls_from_seed(seed.as_bytes(), path).unwrap();
+
+    assert!(extended_key.address.starts_with("f3"));
+    assert_eq!(extended_key.address, extended_key_expected.address);
+    assert_eq!(
+        base64::encode(&extended_key.private_key.0),
+        base64::encode(&extended_key_expected.private_key.0)
+    );
+
+    // The derived key must be usable as a regular BLS key
+    let recovered_key = key_recover_bls(&extended_key.private_key, false).unwrap();
+    assert_eq!(recovered_key.address, extended_key.address);
+
+    let other_key = key_derive_bls(&mnemonic, "m/12381/461/0/1", "", language_code).unwrap();
+    assert_ne!(other_key.address, extended_key.address);
+}
+
+#[test]
+fn derive_key_bls_testnet() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let mnemonic = test_value["mnemonic"].as_str().unwrap();
+    let language_code = test_value["language_code"].as_str().unwrap();
+
+    let extended_key = key_derive_bls(&mnemonic, "m/12381/1/0/0", "", language_code).unwrap();
+
+    assert!(extended_key.address.starts_with("t3"));
+}
+
+#[test]
+fn derive_key_bls_invalid_path() {
+    let test_value = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let mnemonic = test_value["mnemonic"].as_str().unwrap();
+    let language_code = test_value["language_code"].as_str().unwrap();
+
+    assert!(key_derive_bls(&mnemonic, "m/44'/461'/0/0/0", "", language_code).is_err());
+}
+
+#[test]
+fn generate_bls_key() {
+    let extended_key = key_generate_bls(true).unwrap();
+    assert!(extended_key.address.starts_with("t3"));
+
+    let recovered_key = key_recover_bls(&extended_key.private_key, true).unwrap();
+    assert_eq!(recovered_key.address, extended_key.address);
+
+    let other_key = key_generate_bls(false).unwrap();
+    assert!(other_key.address.starts_with("f3"));
+    assert_ne!(other_key.private_key.0, extended_key.private_key.0);
+}
+
 #[test]
 fn parse_unsigned_transaction() {
     let test_value = common::load_test_vectors("../test_vectors/txs.json").unwrap();
@@ -281,6 +787,74 @@ fn verify_invalid_signature() {
     assert!(valid_signature.is_err() || !valid_signature.unwrap());
 }
 
+#[test]
+fn verify_mainnet_signatures() {
+    let wallet = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let private_key =
+        PrivateKey::try_from(wallet["private_key"].as_str().unwrap().to_string()).unwrap();
+
+    let message = UnsignedMessageAPI {
+        to: "f17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy".to_string(),
+        from: "f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba".to_string(),
+        nonce: 1,
+        value: "100000".to_string(),
+        gas_limit: 25000,
+        gas_fee_cap: "2500".to_string(),
+        gas_premium: "2500".to_string(),
+        method: 0,
+        params: "".to_string(),
+    };
+
+    let signed_message = transaction_sign(&message, &private_key).unwrap();
+    let signature = Signature::try_from(signed_message.signature.data.clone()).unwrap();
+    let message_cbor = transaction_serialize(&message).unwrap();
+
+    assert!(verify_signature(&signature, &message_cbor).unwrap());
+    assert!(verify_signed_message(&signed_message).unwrap());
+
+    // The same message on testnet has the same signature
+    let testnet_signed_message = SignedMessageAPI {
+        message: UnsignedMessageAPI {
+            to: "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy".to_string(),
+            from: "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba".to_string(),
+            ..message.clone()
+        },
+        signature: signed_message.signature.clone(),
+    };
+    assert!(verify_signed_message(&testnet_signed_message).unwrap());
+
+    // Tampered message
+    let tampered_message = SignedMessageAPI {
+        message: UnsignedMessageAPI {
+            nonce: 2,
+            ..message.clone()
+        },
+        signature: signed_message.signature.clone(),
+    };
+    assert!(!verify_signed_message(&tampered_message).unwrap());
+
+    // Wrong signature type
+    let mut wrong_type = signed_message.clone();
+    wrong_type.signature.sig_type = 2;
+    assert!(!verify_signed_message(&wrong_type).unwrap());
+
+    // BLS
+    let test_value = common::load_test_vectors("../test_vectors/bls_wallet.json").unwrap();
+    let bls_key =
+        PrivateKey::try_from(test_value["bls_private_key"].as_str().unwrap().to_string()).unwrap();
+    let bls_message = UnsignedMessageAPI {
+        from: key_recover_bls(&bls_key, false).unwrap().address,
+        ..message
+    };
+
+    let signed_message = transaction_sign(&bls_message, &bls_key).unwrap();
+    let signature = Signature::try_from(signed_message.signature.data.clone()).unwrap();
+    let message_cbor = transaction_serialize(&bls_message).unwrap();
+
+    assert!(verify_signature(&signature, &message_cbor).unwrap());
+    assert!(verify_signed_message(&signed_message).unwrap());
+}
+
 #[test]
 fn sign_bls_transaction() {
     let test_value = common::load_test_vectors("../test_vectors/bls_wallet.json").unwrap();
@@ -397,6 +971,76 @@ fn test_verify_aggregated_signature() {
     assert!(verify_aggregated_signature(&sig, &cbor_messages[..]).unwrap());
 }
 
+#[test]
+fn aggregate_bls_signed_messages() {
+    let num_messages = 3;
+
+    let mut rng = ChaCha8Rng::seed_from_u64(12);
+
+    let private_keys: Vec<_> = (0..num_messages)
+        .map(|_| {
+            let bls_private_key = bls_signatures::PrivateKey::generate(&mut rng);
+            PrivateKey::try_from(bls_private_key.as_bytes()).unwrap()
+        })
+        .collect();
+
+    let signed_messages: Vec<SignedMessageAPI> = private_keys
+        .iter()
+        .map(|private_key| {
+            let bls_address = key_recover_bls(private_key, true).unwrap().address;
+
+            let message = UnsignedMessageAPI {
+                to: "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy".to_string(),
+                from: bls_address,
+                nonce: 1,
+                value: "100000".to_string(),
+                gas_limit: 25000,
+                gas_fee_cap: "2500".to_string(),
+                gas_premium: "2500".to_string(),
+                method: 0,
+                params: "".to_string(),
+            };
+
+            transaction_sign(&message, private_key).unwrap()
+        })
+        .collect();
+
+    let aggregate = aggregate_bls_messages(&signed_messages).unwrap();
+
+    let cbor_messages: Vec<CborBuffer> = signed_messages
+        .iter()
+        .map(|signed_message| transaction_serialize(&signed_message.message).unwrap())
+        .collect();
+    let sig = SignatureBLS::try_from(aggregate.signature.data.clone()).unwrap();
+    assert!(verify_aggregated_signature(&sig, &cbor_messages[..]).unwrap());
+
+    for (cid, signed_message) in aggregate.cids.iter().zip(signed_messages.iter()) {
+        let message_api = MessageTxAPI::UnsignedMessageAPI(signed_message.message.clone());
+        assert_eq!(cid, &get_cid(message_api).unwrap());
+    }
+
+    let signatures: Vec<SignatureBLS> = signed_messages
+        .iter()
+        .map(|signed_message| {
+            SignatureBLS::try_from(signed_message.signature.data.clone()).unwrap()
+        })
+        .collect();
+    let sig_from_signatures = aggregate_bls_signatures(&signatures).unwrap();
+    assert_eq!(sig_from_signatures.as_bytes(), aggregate.signature.data);
+
+    assert!(aggregate_bls_signatures(&[]).is_err());
+    assert!(aggregate_bls_messages(&[]).is_err());
+
+    // secp256k1 signed messages can't be aggregated
+    let secp256k1_private_key =
+        PrivateKey::try_from("8VcW07ADswS4BV2cxi5rnIadVsyTDDhY1NfDH19T8Uo=".to_string()).unwrap();
+    let mut message = signed_messages[0].message.clone();
+    message.from = "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba".to_string();
+    let mut mixed_messages = signed_messages.clone();
+    mixed_messages.push(transaction_sign(&message, &secp256k1_private_key).unwrap());
+    assert!(aggregate_bls_messages(&mixed_messages).is_err());
+}
+
 #[test]
 fn payment_channel_creation_bls_signing() {
     let test_value = common::load_test_vectors("../test_vectors/payment_channel.json").unwrap();
@@ -688,6 +1332,212 @@ fn test_sign_voucher() {
     );
 }
 
+#[test]
+fn sign_with_signer_backend() {
+    let wallet = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let mnemonic = wallet["mnemonic"].as_str().unwrap();
+    let language_code = wallet["language_code"].as_str().unwrap();
+
+    let extended_key = key_derive(mnemonic, "m/44'/461'/0/0/0", "", language_code).unwrap();
+    let signer = PrivateKeySigner::Secp256k1(&extended_key.private_key);
+
+    let message = UnsignedMessageAPI {
+        to: "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy".to_string(),
+        from: extended_key.address.clone(),
+        nonce: 1,
+        value: "100000".to_string(),
+        gas_limit: 25000,
+        gas_fee_cap: "2500".to_string(),
+        gas_premium: "2500".to_string(),
+        method: 0,
+        params: "".to_string(),
+    };
+
+    let signed_message = transaction_sign_with_signer(&message, &signer).unwrap();
+    let expected = transaction_sign(&message, &extended_key.private_key).unwrap();
+    assert_eq!(signed_message.signature.data, expected.signature.data);
+
+    // The signer must hold the key of the sender
+    let other_key = key_derive(mnemonic, "m/44'/461'/0/0/1", "", language_code).unwrap();
+    let other_signer = PrivateKeySigner::Secp256k1(&other_key.private_key);
+    assert!(transaction_sign_raw_with_signer(&message, &other_signer).is_err());
+
+    // BLS
+    let test_value = common::load_test_vectors("../test_vectors/bls_wallet.json").unwrap();
+    let bls_key =
+        PrivateKey::try_from(test_value["bls_private_key"].as_str().unwrap().to_string()).unwrap();
+    let bls_signer = PrivateKeySigner::Bls(&bls_key);
+
+    let bls_message = UnsignedMessageAPI {
+        from: bls_signer.address().unwrap().to_string(),
+        ..message
+    };
+    let raw_sig = transaction_sign_raw_with_signer(&bls_message, &bls_signer).unwrap();
+    let expected = transaction_sign_raw(&bls_message, &bls_key).unwrap();
+    assert_eq!(raw_sig.as_bytes(), expected.as_bytes());
+
+    // Vouchers
+    let voucher = create_voucher(
+        "t24acjqhdetck7irsvmn2p6jpuwnouzjxuoa22rva".to_string(),
+        0,
+        0,
+        "10000".to_string(),
+        0,
+        1,
+        0,
+    )
+    .unwrap();
+
+    let signed_voucher = sign_voucher_with_signer(voucher.clone(), &signer).unwrap();
+    assert_eq!(
+        signed_voucher,
+        sign_voucher(voucher.clone(), &extended_key.private_key).unwrap()
+    );
+
+    let signed_voucher = sign_voucher_with_signer(voucher, &bls_signer).unwrap();
+    assert!(verify_voucher_signature(signed_voucher, bls_message.from).unwrap());
+}
+
+#[test]
+fn sign_bytes_with_signer_backend() {
+    let wallet = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let private_key =
+        PrivateKey::try_from(wallet["private_key"].as_str().unwrap().to_string()).unwrap();
+    let signer = PrivateKeySigner::Secp256k1(&private_key);
+
+    let signature = sign_bytes_with_signer(b"filecoin", &signer, false).unwrap();
+
+    let digest = forest_encoding::blake2b_256(b"filecoin");
+    let signature_bytes = signature.as_bytes();
+    let public_key = secp256k1::recover(
+        &secp256k1::Message::parse(&digest),
+        &secp256k1::Signature::parse_slice(&signature_bytes[..64]).unwrap(),
+        &secp256k1::RecoveryId::parse(signature_bytes[64]).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        Address::new_secp256k1(&public_key.serialize()).unwrap(),
+        signer.address().unwrap()
+    );
+}
+
+#[test]
+fn sign_and_verify_bytes() {
+    let wallet = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let private_key =
+        PrivateKey::try_from(wallet["private_key"].as_str().unwrap().to_string()).unwrap();
+    let address = "f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba";
+
+    // Secp256k1 keys sign the blake2b-256 digest of the data
+    let signature = sign_bytes(b"filecoin", address, &private_key, false).unwrap();
+    assert_eq!(
+        hex::encode(signature.as_bytes()),
+        "1887e6beb5e42c3bd60737a3a2155f24c0aed8082db3ba676fb4a1683f17b5937dcdcdac84abd0bcc7fb8fddc1fe718d09ad691261c62a7d2bc176096ed32d0f01"
+    );
+    assert!(verify_bytes(&signature, address, b"filecoin", false).unwrap());
+    assert!(!verify_bytes(&signature, address, b"filecoin", true).unwrap());
+    assert!(!verify_bytes(&signature, address, b"filecoins", false).unwrap());
+    assert!(!verify_bytes(
+        &signature,
+        "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy",
+        b"filecoin",
+        false
+    )
+    .unwrap());
+
+    // Domain separation prefix
+    let signature = sign_bytes(b"filecoin", address, &private_key, true).unwrap();
+    assert_eq!(
+        hex::encode(signature.as_bytes()),
+        "0bdd06faac80f34da5308b5331b54e8dba4a6f452ef5af0b300dea074f9dff9642f001ad666c7cfde8f45275d4959d9b46398b923fa03e1c662c9f5901183eef01"
+    );
+    assert!(verify_bytes(&signature, address, b"filecoin", true).unwrap());
+    assert!(!verify_bytes(&signature, address, b"filecoin", false).unwrap());
+
+    // The private key must match the address
+    assert!(sign_bytes(
+        b"filecoin",
+        "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy",
+        &private_key,
+        false
+    )
+    .is_err());
+
+    // BLS keys sign the data
+    let test_value = common::load_test_vectors("../test_vectors/bls_wallet.json").unwrap();
+    let bls_key =
+        PrivateKey::try_from(test_value["bls_private_key"].as_str().unwrap().to_string()).unwrap();
+    let bls_address = key_recover_bls(&bls_key, true).unwrap().address;
+
+    let signature = sign_bytes(b"filecoin", &bls_address, &bls_key, true).unwrap();
+    assert!(verify_bytes(&signature, &bls_address, b"filecoin", true).unwrap());
+    assert!(!verify_bytes(&signature, &bls_address, b"filecoin", false).unwrap());
+    assert!(verify_bytes(&signature, address, b"filecoin", true).is_err());
+}
+
+#[test]
+fn recover_message_and_voucher_signer() {
+    let wallet = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let private_key =
+        PrivateKey::try_from(wallet["private_key"].as_str().unwrap().to_string()).unwrap();
+    let extended_key = key_recover(&private_key, true).unwrap();
+
+    let message = UnsignedMessageAPI {
+        to: "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy".to_string(),
+        from: extended_key.address.clone(),
+        nonce: 1,
+        value: "100000".to_string(),
+        gas_limit: 25000,
+        gas_fee_cap: "2500".to_string(),
+        gas_premium: "2500".to_string(),
+        method: 0,
+        params: "".to_string(),
+    };
+
+    let signature = transaction_sign_raw(&message, &private_key).unwrap();
+    let cbor_buffer = transaction_serialize(&message).unwrap();
+
+    let signer = recover_signer(&signature, &cbor_buffer, false).unwrap();
+    assert_eq!(signer.address, "f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba");
+    assert_eq!(signer.public_key.to_vec(), extended_key.public_key.to_vec());
+
+    let signed_message = transaction_sign(&message, &private_key).unwrap();
+    let signer = recover_signed_message_signer(&signed_message).unwrap();
+    assert_eq!(signer.address, extended_key.address);
+
+    // Another message gives another signer
+    let other_message = UnsignedMessageAPI {
+        nonce: 2,
+        ..message
+    };
+    let other_cbor = transaction_serialize(&other_message).unwrap();
+    let other_signer = recover_signer(&signature, &other_cbor, true).unwrap();
+    assert_ne!(other_signer.address, extended_key.address);
+
+    // BLS signatures
+    let test_value = common::load_test_vectors("../test_vectors/bls_signature.json").unwrap();
+    let bls_signature =
+        Signature::try_from(test_value["sig"].as_str().unwrap().to_string()).unwrap();
+    assert!(recover_signer(&bls_signature, &cbor_buffer, true).is_err());
+
+    // Vouchers
+    let voucher = create_voucher(
+        "t24acjqhdetck7irsvmn2p6jpuwnouzjxuoa22rva".to_string(),
+        0,
+        0,
+        "10000".to_string(),
+        0,
+        1,
+        0,
+    )
+    .unwrap();
+    assert!(recover_voucher_signer(voucher.clone(), true).is_err());
+
+    let signed_voucher = sign_voucher(voucher, &private_key).unwrap();
+    let signer = recover_voucher_signer(signed_voucher, true).unwrap();
+    assert_eq!(signer.address, extended_key.address);
+}
+
 #[test]
 fn support_multisig_create() {
     let test_value = common::load_test_vectors("../test_vectors/multisig.json").unwrap();
@@ -912,6 +1762,67 @@ fn support_multisig_cancel_message() {
     assert_eq!(hex::encode(&result), test_value["cancel"]["cbor"]);
 }
 
+#[test]
+fn builders_accept_fil_units() {
+    let from = "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba".to_string();
+    let to = "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy".to_string();
+
+    let create = |value: &str, gas_fee_cap: &str| {
+        create_multisig(
+            from.clone(),
+            vec![from.clone(), to.clone()],
+            value.to_string(),
+            1,
+            1,
+            -1,
+            0,
+            1000000,
+            gas_fee_cap.to_string(),
+            "2500".to_string(),
+        )
+    };
+
+    let multisig_create_message = create("1.5 FIL", "1 nanoFIL").unwrap();
+    assert_eq!(multisig_create_message.value, "1500000000000000000");
+    assert_eq!(multisig_create_message.gas_fee_cap, "1000000000");
+    assert_eq!(
+        multisig_create_message,
+        create("1500000000000000000", "1000000000").unwrap()
+    );
+    assert!(create("1.5 EUR", "2500").is_err());
+    assert!(create("1.5", "2500").is_err());
+
+    let propose = |amount: &str| {
+        proposal_multisig_message(
+            "t01004".to_string(),
+            to.clone(),
+            from.clone(),
+            amount.to_string(),
+            2,
+            1000000,
+            "2500".to_string(),
+            "2500".to_string(),
+        )
+        .unwrap()
+    };
+    assert_eq!(propose("200 nanoFIL"), propose("200000000000"));
+    assert_ne!(propose("200 nanoFIL"), propose("200"));
+
+    let voucher = |amount: &str| {
+        create_voucher(
+            "t24acjqhdetck7irsvmn2p6jpuwnouzjxuoa22rva".to_string(),
+            0,
+            0,
+            amount.to_string(),
+            0,
+            1,
+            0,
+        )
+        .unwrap()
+    };
+    assert_eq!(voucher("12 attoFIL"), voucher("12"));
+}
+
 #[test]
 fn test_verify_voucher_signature() {
     let test_value = common::load_test_vectors("../test_vectors/voucher.json").unwrap();
@@ -943,6 +1854,73 @@ fn test_get_cid() {
     assert_eq!(cid, expected_cid);
 }
 
+#[test]
+fn predict_exec_robust_address() {
+    let multisig_value = common::load_test_vectors("../test_vectors/multisig.json").unwrap();
+    let pymtchan_value = common::load_test_vectors("../test_vectors/payment_channel.json").unwrap();
+
+    let multisig_create_message: UnsignedMessageAPI =
+        serde_json::from_value(multisig_value["create"]["message"].to_owned())
+            .expect("Could not serialize unsigned message");
+
+    assert_eq!(
+        predict_robust_address(&multisig_create_message).unwrap(),
+        "t2nzl23ysfwo53kl5syolbeh6j43sxx4hfmdhm56i"
+    );
+
+    let mut mainnet_message = multisig_create_message.clone();
+    mainnet_message.from = "f1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba".to_string();
+    mainnet_message.nonce = 0;
+    assert_eq!(
+        predict_robust_address(&mainnet_message).unwrap(),
+        "f2h6o4uvzsksf3yi2ri2uu7eqvhqkcp7axmg3mski"
+    );
+
+    let tc_creation_bls = pymtchan_value["creation"]["bls"].to_owned();
+    let pch_create_message = create_pymtchan(
+        tc_creation_bls["constructor_params"]["from"]
+            .as_str()
+            .unwrap()
+            .to_string(),
+        tc_creation_bls["constructor_params"]["to"]
+            .as_str()
+            .unwrap()
+            .to_string(),
+        "1".to_string(),
+        1,
+        200000000,
+        "2500".to_string(),
+        "2500".to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        predict_robust_address(&pch_create_message).unwrap(),
+        "t2bcfilfrnxvqbapfxkywjfrr3bx7usfzg35zmtvy"
+    );
+
+    let mut delegated_message = mainnet_message.clone();
+    delegated_message.from = "f410fkkld55ioe7qg24wvt7fu6pbknb56ht7pt4zamxa".to_string();
+    assert_eq!(
+        predict_robust_address(&delegated_message).unwrap(),
+        "f2sln7a3u6z3qdgn4cta4ykrlasgv7wfdiwlisaeq"
+    );
+
+    // ID addresses cannot be resolved offline
+    let mut id_message = mainnet_message.clone();
+    id_message.from = "f01234".to_string();
+    assert!(predict_robust_address(&id_message).is_err());
+
+    // Not an Init actor `Exec` call
+    let mut send_message = mainnet_message.clone();
+    send_message.method = 0;
+    assert!(predict_robust_address(&send_message).is_err());
+
+    let mut other_actor_message = mainnet_message;
+    other_actor_message.to = "f02".to_string();
+    assert!(predict_robust_address(&other_actor_message).is_err());
+}
+
 #[test]
 fn test_multisig_v1_deserialize() {
     let expected_params = multisig::ConstructorParams {
@@ -965,3 +1943,167 @@ fn test_multisig_v1_deserialize() {
         MessageParams::ConstructorParamsMultisig(expected_params.into())
     );
 }
+
+#[test]
+fn strict_signing_refuses_invalid_messages() {
+    let wallet = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let private_key =
+        PrivateKey::try_from(wallet["private_key"].as_str().unwrap().to_string()).unwrap();
+
+    let mut unsigned_message = UnsignedMessageAPI {
+        to: "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy".to_string(),
+        from: "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba".to_string(),
+        nonce: 1,
+        value: "100000".to_string(),
+        gas_limit: 25000,
+        gas_fee_cap: "2500".to_string(),
+        gas_premium: "2500".to_string(),
+        method: 0,
+        params: "".to_string(),
+    };
+
+    assert!(message_validate(&unsigned_message, None).is_valid());
+    assert_eq!(
+        transaction_sign_strict(&unsigned_message, &private_key).unwrap(),
+        transaction_sign(&unsigned_message, &private_key).unwrap()
+    );
+
+    unsigned_message.gas_premium = "5000".to_string();
+    let validation = message_validate(&unsigned_message, None);
+    assert_eq!(validation.errors.len(), 1);
+    assert_eq!(validation.errors[0].field, "gas_premium");
+    assert_eq!(
+        validation.errors[0].kind,
+        MessageIssueKind::GasPremiumAboveFeeCap
+    );
+
+    // Only the strict mode refuses to sign
+    assert!(transaction_sign(&unsigned_message, &private_key).is_ok());
+    assert!(transaction_sign_strict(&unsigned_message, &private_key).is_err());
+    assert!(transaction_sign_raw_strict(&unsigned_message, &private_key).is_err());
+    assert!(transaction_sign_with_signer_strict(
+        &unsigned_message,
+        &PrivateKeySigner::Secp256k1(&private_key)
+    )
+    .is_err());
+}
+
+#[test]
+fn sign_and_verify_batch() {
+    let wallet = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let private_key =
+        PrivateKey::try_from(wallet["private_key"].as_str().unwrap().to_string()).unwrap();
+
+    let mut unsigned_messages: Vec<UnsignedMessageAPI> = (0..4)
+        .map(|nonce| UnsignedMessageAPI {
+            to: "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy".to_string(),
+            from: "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba".to_string(),
+            nonce,
+            value: "100000".to_string(),
+            gas_limit: 25000,
+            gas_fee_cap: "2500".to_string(),
+            gas_premium: "2500".to_string(),
+            method: 0,
+            params: "".to_string(),
+        })
+        .collect();
+    unsigned_messages[2].to = "t1invalid".to_string();
+
+    let signed_messages = transaction_sign_batch(&unsigned_messages, &private_key);
+    assert_eq!(signed_messages.len(), 4);
+    assert!(signed_messages[2].is_err());
+
+    let signatures: Vec<(Signature, CborBuffer)> = signed_messages
+        .into_iter()
+        .filter_map(Result::ok)
+        .map(|signed_message| {
+            assert_eq!(
+                signed_message,
+                transaction_sign(&signed_message.message, &private_key).unwrap()
+            );
+
+            let signature = Signature::try_from(signed_message.signature.data).unwrap();
+            let cbor = transaction_serialize(&signed_message.message).unwrap();
+
+            (signature, cbor)
+        })
+        .collect();
+    assert_eq!(signatures.len(), 3);
+
+    let valid = verify_signature_batch(&signatures);
+    assert!(valid.iter().all(|result| *result.as_ref().unwrap()));
+
+    // Swap the messages of the first two signatures
+    let (signature_0, cbor_0) = &signatures[0];
+    let (signature_1, cbor_1) = &signatures[1];
+    let swapped = vec![
+        (
+            Signature::try_from(signature_0.as_bytes()).unwrap(),
+            CborBuffer(cbor_1.0.clone()),
+        ),
+        (
+            Signature::try_from(signature_1.as_bytes()).unwrap(),
+            CborBuffer(cbor_0.0.clone()),
+        ),
+        (
+            Signature::try_from(signature_1.as_bytes()).unwrap(),
+            CborBuffer(vec![0x00]),
+        ),
+    ];
+
+    let valid = verify_signature_batch(&swapped);
+    assert!(!valid[0].as_ref().unwrap());
+    assert!(!valid[1].as_ref().unwrap());
+    assert!(valid[2].is_err());
+}
+
+#[test]
+fn strict_verification_and_der_signatures() {
+    let wallet = common::load_test_vectors("../test_vectors/wallet.json").unwrap();
+    let private_key =
+        PrivateKey::try_from(wallet["private_key"].as_str().unwrap().to_string()).unwrap();
+
+    let message = UnsignedMessageAPI {
+        to: "t17uoq6tp427uzv7fztkbsnn64iwotfrristwpryy".to_string(),
+        from: "t1d2xrzcslx7xlbbylc5c3d5lvandqw4iwl6epxba".to_string(),
+        nonce: 1,
+        value: "100000".to_string(),
+        gas_limit: 25000,
+        gas_fee_cap: "2500".to_string(),
+        gas_premium: "2500".to_string(),
+        method: 0,
+        params: "".to_string(),
+    };
+    let cbor = transaction_serialize(&message).unwrap();
+
+    let signature = match transaction_sign_raw(&message, &private_key).unwrap() {
+        Signature::SignatureSECP256K1(sig_secp256k1) => sig_secp256k1,
+        Signature::SignatureBLS(_) => panic!("unexpected BLS signature"),
+    };
+
+    // Same signature with a high s
+    let mut high_s = secp256k1::Signature::parse(&signature.to_compact());
+    high_s.s = -&high_s.s;
+    let high_s =
+        SignatureSECP256K1::from_compact(&high_s.serialize(), signature.recovery_id() ^ 1).unwrap();
+
+    let low_s = Signature::SignatureSECP256K1(SignatureSECP256K1 { 0: signature.0 });
+    assert!(verify_signature(&low_s, &cbor).unwrap());
+    assert!(verify_signature_strict(&low_s, &cbor).unwrap());
+
+    let der = high_s.to_der().unwrap();
+    let malleable = Signature::SignatureSECP256K1(high_s);
+    assert!(verify_signature(&malleable, &cbor).unwrap());
+    assert!(!verify_signature_strict(&malleable, &cbor).unwrap());
+
+    // A DER signature (e.g from an HSM) is normalized and gets the recovery ID of the sender
+    let from_der = transaction_signature_from_der(&der, &cbor).unwrap();
+    assert_eq!(from_der.0[..], signature.0[..]);
+
+    let other_cbor = transaction_serialize(&UnsignedMessageAPI {
+        nonce: 2,
+        ..message
+    })
+    .unwrap();
+    assert!(transaction_signature_from_der(&der, &other_cbor).is_err());
+}